@@ -0,0 +1,236 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope, PointerAncestor};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+pub struct AddedRequiredAllOfMemberCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+/// Whether schema at pointer is a new member of an existing schema `allOf`
+fn is_added_all_of_member(pointer: &PathPointer) -> bool {
+    let components = &pointer.components;
+    let len = components.len();
+
+    // [.., allOf, index, schema]
+    len >= 3
+        && components[len - 3].scope == Some(PathPointerScope::SchemaAllOf)
+        && components[len - 2].kind.is_added()
+        && pointer.ancestor(PointerAncestor::Relative(3)).is_updated()
+}
+
+impl<'s> DiffVisitor<'s> for AddedRequiredAllOfMemberCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        request_body_diff_result: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        if !pointer.is_updated() || !pointer.is_request() {
+            return false;
+        }
+
+        // Schemas added to required bodies are already reported by
+        // `added-required-body-property`
+        let is_required = request_body_diff_result
+            .get()
+            .and_then(|request_body| request_body.required.get())
+            .copied()
+            .unwrap_or(false);
+
+        !is_required
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if is_added_all_of_member(pointer) {
+            let required: Vec<_> = schema_diff_result
+                .get()
+                .and_then(|schema| schema.required.get_new())
+                .map(|required| {
+                    required
+                        .iter()
+                        .filter(|name| !name.is_removed())
+                        .filter_map(|name| name.get().cloned())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if !required.is_empty() {
+                self.pointers.borrow_mut().push((
+                    pointer.clone(),
+                    None,
+                    Some(Value::from(required)),
+                ));
+            }
+            return false;
+        }
+
+        pointer.is_updated()
+    }
+}
+
+impl Default for AddedRequiredAllOfMemberCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for AddedRequiredAllOfMemberCheck {
+    fn id(&self) -> &'static str {
+        "added-required-all-of-member"
+    }
+
+    fn description(&self) -> &'static str {
+        "Required `allOf` member added to request schema"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::added_required_all_of_member_check::AddedRequiredAllOfMemberCheck;
+    use crate::checker::{validate, ValidationIssuer};
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_added_required_all_of_member_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/composition/schema-with-compositions.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/composition/schema-with-compositions-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = AddedRequiredAllOfMemberCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/owner/allOf/1",
+        );
+        assert_eq!(issues[0].old_value, None);
+        assert_eq!(issues[0].new_value, Some(json!(["licence"])));
+
+        // The member added to the required `put` body is reported as an
+        // added required body property only
+        let issues = validate(
+            diff.get().unwrap(),
+            &[
+                "added-required-all-of-member",
+                "added-required-body-property",
+            ],
+        );
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.kind.as_ref(), issue.path.get_path()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "added-required-body-property",
+                    "paths//pets/put/requestBody/content/application/json/schema/allOf/1".to_owned()
+                ),
+                (
+                    "added-required-all-of-member",
+                    "paths//pets/post/requestBody/content/application/json/schema/properties/owner/allOf/1".to_owned()
+                ),
+            ]
+        );
+    }
+}
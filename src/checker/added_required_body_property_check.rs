@@ -1,16 +1,19 @@
 use std::cell::RefCell;
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::checker::{
+    schema_value, ValidationIssue, ValidationIssuer, ValueChange,
+};
 use crate::core::{DiffResult, MapDiff};
 use crate::path_pointer::PathPointer;
 use crate::schema_diff::{
-    MediaTypeDiff, OperationDiff, RequestBodyDiff, SchemaDiff,
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
 };
 
 use crate::visitor::DiffVisitor;
 
 pub struct AddedRequiredBodyPropertyCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for AddedRequiredBodyPropertyCheck {
@@ -28,21 +31,26 @@ impl<'s> DiffVisitor<'s> for AddedRequiredBodyPropertyCheck {
         pointer: &PathPointer,
         request_body_diff_result: &'s DiffResult<RequestBodyDiff>,
     ) -> bool {
-        if !pointer.is_updated() {
+        if !pointer.is_updated() || !pointer.is_request() {
             return false;
         }
         // Continue only if request body is required
         if let Some(request_body_diff) = request_body_diff_result.get() {
-            request_body_diff
-                .required
-                .get()
-                .map(|v| *v)
-                .unwrap_or(false)
+            request_body_diff.required.get().copied().unwrap_or(false)
         } else {
             false
         }
     }
 
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        // Webhook responses are sent by the consumer
+        pointer.is_updated() && pointer.is_request()
+    }
+
     fn visit_media_types(
         &self,
         pointer: &PathPointer,
@@ -62,10 +70,16 @@ impl<'s> DiffVisitor<'s> for AddedRequiredBodyPropertyCheck {
     fn visit_schema(
         &self,
         pointer: &PathPointer,
-        _schema_diff_result: &'s DiffResult<SchemaDiff>,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
     ) -> bool {
         if pointer.is_added() {
-            self.pointers.borrow_mut().push(pointer.clone());
+            self.pointers.borrow_mut().push((
+                pointer.clone(),
+                None,
+                schema_diff_result
+                    .get()
+                    .map(|schema| schema_value(schema, true)),
+            ));
             return false;
         }
 
@@ -86,6 +100,10 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredBodyPropertyCheck {
         "added-required-body-property"
     }
 
+    fn description(&self) -> &'static str {
+        "Required property added to request body"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -95,7 +113,10 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredBodyPropertyCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -104,6 +125,8 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredBodyPropertyCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::added_required_body_property_check::AddedRequiredBodyPropertyCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -133,14 +156,17 @@ mod tests {
         assert_eq!(issues.len(), 2);
         // new schema to allOf added
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
-            "paths//test/put/requestBody/content/application/json/schema/properties/field1/allOf/0",
+            issues.first().unwrap().path.get_path(),
+            "paths//test/put/requestBody/content/application/json/schema/properties/field1/allOf/2",
         );
+        assert_eq!(issues[0].new_value, Some(json!({"enum": ["val1"]})));
         // new property added
         assert_eq!(
             issues.get(1).unwrap().path.get_path(),
             "paths//test/put/requestBody/content/application/json/schema/properties/field2",
         );
+        assert_eq!(issues[1].old_value, None);
+        assert_eq!(issues[1].new_value, Some(json!({"type": "string"})));
     }
 
     #[test]
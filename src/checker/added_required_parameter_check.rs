@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
 use crate::core::{DiffResult, VecDiff};
 use crate::path_pointer::PathPointer;
 use crate::schema_diff::{MayBeRefDiff, OperationDiff, ParameterDiff};
@@ -8,7 +10,7 @@ use crate::schema_diff::{MayBeRefDiff, OperationDiff, ParameterDiff};
 use crate::visitor::DiffVisitor;
 
 pub struct AddedRequiredParameterCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for AddedRequiredParameterCheck {
@@ -26,7 +28,8 @@ impl<'s> DiffVisitor<'s> for AddedRequiredParameterCheck {
         pointer: &PathPointer,
         _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
     ) -> bool {
-        pointer.is_updated()
+        // Webhook parameters are sent by the API, not by its consumers
+        pointer.is_updated() && pointer.is_request()
     }
 
     fn visit_parameter(
@@ -43,7 +46,14 @@ impl<'s> DiffVisitor<'s> for AddedRequiredParameterCheck {
             if parameter_diff.required.is_upserted() {
                 if let Some(required) = parameter_diff.required.get() {
                     if *required {
-                        self.pointers.borrow_mut().push(pointer.clone())
+                        self.pointers.borrow_mut().push((
+                            pointer.clone(),
+                            parameter_diff
+                                .required
+                                .get_old()
+                                .map(|required| Value::Bool(*required)),
+                            Some(Value::Bool(true)),
+                        ))
                     }
                 }
             }
@@ -66,6 +76,10 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredParameterCheck {
         "added-required-parameter"
     }
 
+    fn description(&self) -> &'static str {
+        "Required parameter added"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -75,7 +89,10 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredParameterCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -84,6 +101,8 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredParameterCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::added_required_parameter_check::AddedRequiredParameterCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -113,13 +132,16 @@ mod tests {
         assert_eq!(issues.len(), 2);
         // `param4defaultToUpdate` updated to required=true
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
+            issues.first().unwrap().path.get_path(),
             "paths//test/get/parameters/1",
         );
+        assert_eq!(issues[0].old_value, None);
+        assert_eq!(issues[0].new_value, Some(json!(true)));
         // `param6newRequired` added with required=true
         assert_eq!(
             issues.get(1).unwrap().path.get_path(),
             "paths//test/get/parameters/5",
         );
+        assert_eq!(issues[1].new_value, Some(json!(true)));
     }
 }
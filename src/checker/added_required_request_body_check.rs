@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
 use crate::core::DiffResult;
 use crate::path_pointer::PathPointer;
 use crate::schema_diff::{OperationDiff, RequestBodyDiff};
@@ -8,7 +10,7 @@ use crate::schema_diff::{OperationDiff, RequestBodyDiff};
 use crate::visitor::DiffVisitor;
 
 pub struct AddedRequiredRequestBodyCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for AddedRequiredRequestBodyCheck {
@@ -27,7 +29,7 @@ impl<'s> DiffVisitor<'s> for AddedRequiredRequestBodyCheck {
         pointer: &PathPointer,
         request_body_diff_result: &'s DiffResult<RequestBodyDiff>,
     ) -> bool {
-        if !pointer.is_upserted() {
+        if !pointer.is_upserted() || !pointer.is_request() {
             return false;
         }
 
@@ -41,7 +43,14 @@ impl<'s> DiffVisitor<'s> for AddedRequiredRequestBodyCheck {
 
         if let Some(required) = request_body_diff.required.get() {
             if *required {
-                self.pointers.borrow_mut().push(pointer.clone())
+                self.pointers.borrow_mut().push((
+                    pointer.clone(),
+                    request_body_diff
+                        .required
+                        .get_old()
+                        .map(|required| Value::Bool(*required)),
+                    Some(Value::Bool(true)),
+                ))
             }
         }
 
@@ -62,6 +71,10 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredRequestBodyCheck {
         "added-required-request-body"
     }
 
+    fn description(&self) -> &'static str {
+        "Required request body added"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -71,7 +84,10 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredRequestBodyCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -80,6 +96,8 @@ impl<'s> ValidationIssuer<'s> for AddedRequiredRequestBodyCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::added_required_request_body_check::AddedRequiredRequestBodyCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -108,12 +126,16 @@ mod tests {
 
         assert_eq!(issues.len(), 2);
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
+            issues.first().unwrap().path.get_path(),
             "paths//test/post/requestBody",
         );
+        assert_eq!(issues[0].old_value, Some(json!(false)));
+        assert_eq!(issues[0].new_value, Some(json!(true)));
         assert_eq!(
             issues.get(1).unwrap().path.get_path(),
             "paths//test/patch/requestBody",
         );
+        assert_eq!(issues[1].old_value, None);
+        assert_eq!(issues[1].new_value, Some(json!(true)));
     }
 }
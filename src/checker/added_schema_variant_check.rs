@@ -0,0 +1,189 @@
+use std::cell::RefCell;
+
+use crate::checker::removed_schema_variant_check::composition_variants;
+use crate::checker::{
+    schema_ref_value, ValidationIssue, ValidationIssuer, ValueChange,
+};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+pub struct AddedSchemaVariantCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for AddedSchemaVariantCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_response()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_response()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_response()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        // Clients may not be able to handle the new variant
+        for (variant_pointer, variant) in composition_variants(pointer, schema)
+        {
+            if variant.is_added() {
+                let value = variant
+                    .get()
+                    .and_then(|variant| schema_ref_value(variant, true));
+                self.pointers.borrow_mut().push((
+                    variant_pointer,
+                    None,
+                    value,
+                ));
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for AddedSchemaVariantCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for AddedSchemaVariantCheck {
+    fn id(&self) -> &'static str {
+        "added-schema-variant"
+    }
+
+    fn description(&self) -> &'static str {
+        "Variant added to response `oneOf`/`anyOf`"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::added_schema_variant_check::AddedSchemaVariantCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_added_schema_variant_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/composition/schema-with-compositions.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/composition/schema-with-compositions-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = AddedSchemaVariantCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/responses/200/content/application/json/schema/oneOf/2",
+        );
+        assert_eq!(issues[0].old_value, None);
+        assert_eq!(
+            issues[0].new_value,
+            Some(json!("#/components/schemas/Bird"))
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::cell::RefCell;
+
+use serde_json::{Map, Value};
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::OperationDiff;
+use crate::visitor::DiffVisitor;
+
+pub struct AddedSecurityRequirementCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+fn exists_in<T>(diff_result: &DiffResult<T>, target: bool) -> bool {
+    match diff_result {
+        DiffResult::None => false,
+        DiffResult::Added(_) => target,
+        DiffResult::Removed(_) => !target,
+        _ => true,
+    }
+}
+
+/// Whether operation may be called without credentials on the given side
+fn is_open(
+    security: &DiffResult<VecDiff<MapDiff<VecDiff<String>>>>,
+    target: bool,
+) -> bool {
+    if !exists_in(security, target) {
+        return true;
+    }
+
+    let Some(requirements) = security.get() else {
+        return true;
+    };
+
+    let mut requirements = requirements
+        .iter()
+        .filter(|requirement| exists_in(requirement, target))
+        .peekable();
+
+    if requirements.peek().is_none() {
+        return true;
+    }
+
+    requirements.any(|requirement| {
+        requirement.get().is_none_or(|schemes| {
+            !schemes.values().any(|scopes| exists_in(scopes, target))
+        })
+    })
+}
+
+/// Scopes of a requirement scheme on the given side
+pub(crate) fn scopes_value(
+    scopes: &DiffResult<VecDiff<String>>,
+    target: bool,
+) -> Option<Value> {
+    let scopes = scopes.get_side(target)?;
+    let scopes: Vec<_> = scopes
+        .side(target)
+        .into_iter()
+        .map(String::as_str)
+        .collect();
+    Some(Value::from(scopes))
+}
+
+/// Requirements on the given side in the document form
+fn requirements_value(
+    security: &DiffResult<VecDiff<MapDiff<VecDiff<String>>>>,
+    target: bool,
+) -> Option<Value> {
+    let requirements = security.get_side(target)?;
+    let requirements = requirements
+        .side(target)
+        .into_iter()
+        .map(|schemes| {
+            let schemes: Map<_, _> = schemes
+                .iter()
+                .filter_map(|(name, scopes)| {
+                    Some((name.clone(), scopes_value(scopes, target)?))
+                })
+                .collect();
+            Value::Object(schemes)
+        })
+        .collect();
+    Some(Value::Array(requirements))
+}
+
+/// Scheme names of a requirement on the given side
+fn schemes(
+    requirement: &DiffResult<MapDiff<VecDiff<String>>>,
+    target: bool,
+) -> Vec<&str> {
+    requirement
+        .get()
+        .map(|schemes| {
+            schemes
+                .iter()
+                .filter(|(_, scopes)| exists_in(scopes, target))
+                .map(|(name, _)| name.as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl<'s> DiffVisitor<'s> for AddedSecurityRequirementCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        operation_diff_result: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(operation) = operation_diff_result.get() else {
+            return false;
+        };
+
+        let security_pointer =
+            pointer.add_component(&operation.security, Some("security"), None);
+
+        if is_open(&operation.security, false)
+            && !is_open(&operation.security, true)
+        {
+            self.pointers.borrow_mut().push((
+                security_pointer,
+                requirements_value(&operation.security, false),
+                requirements_value(&operation.security, true),
+            ));
+            return false;
+        }
+
+        let Some(requirements) = operation.security.get() else {
+            return false;
+        };
+
+        let side = |target| -> Vec<_> {
+            requirements
+                .iter()
+                .filter(|requirement| exists_in(requirement, target))
+                .map(|requirement| schemes(requirement, target))
+                .collect()
+        };
+        let new = side(true);
+
+        // Schemes of a requirement are all needed, so users of an old
+        // requirement are locked out unless some new requirement needs no
+        // more schemes. Added alternatives only open the operation wider.
+        let lost = side(false).into_iter().filter(|old| {
+            !new.iter()
+                .any(|new| new.iter().all(|name| old.contains(name)))
+        });
+
+        for old in lost {
+            let added: Vec<_> = requirements
+                .positioned()
+                .filter(|(_, requirement)| requirement.is_added())
+                .collect();
+            let widened: Vec<_> = added
+                .iter()
+                .copied()
+                .filter(|(_, requirement)| {
+                    let new = schemes(requirement, true);
+                    old.iter().all(|name| new.contains(name))
+                })
+                .collect();
+            let candidates = if widened.is_empty() { added } else { widened };
+
+            for (idx, requirement) in candidates {
+                let Some(schemes) = requirement.get() else {
+                    continue;
+                };
+
+                let requirement_pointer =
+                    security_pointer.add(requirement, idx.to_string(), None);
+
+                for (scheme, scopes) in schemes.iter() {
+                    let pointer =
+                        requirement_pointer.add(scopes, scheme, None);
+                    let mut pointers = self.pointers.borrow_mut();
+                    if !old.contains(&scheme.as_str())
+                        && !pointers.iter().any(|(path, ..)| *path == pointer)
+                    {
+                        pointers.push((
+                            pointer,
+                            None,
+                            scopes_value(scopes, true),
+                        ));
+                    }
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl Default for AddedSecurityRequirementCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for AddedSecurityRequirementCheck {
+    fn id(&self) -> &'static str {
+        "added-security-requirement"
+    }
+
+    fn description(&self) -> &'static str {
+        "Security requirement added"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::added_security_requirement_check::AddedSecurityRequirementCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_added_security_requirement_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = AddedSecurityRequirementCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 2);
+        // open operation secured
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//public/get/security",
+        );
+        assert_eq!(issues[0].old_value, None);
+        assert_eq!(issues[0].new_value, Some(json!([{"apiKey": []}])));
+        // scheme ANDed into existing requirement
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "paths//pets/put/security/0/oauth",
+        );
+        assert_eq!(issues[1].old_value, None);
+        assert_eq!(issues[1].new_value, Some(json!(["read"])));
+        // alternative added in front of the existing requirement
+        assert!(issues.iter().all(|issue| !issue
+            .path
+            .get_path()
+            .starts_with("paths//pets/delete")));
+    }
+}
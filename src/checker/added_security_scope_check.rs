@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+
+use crate::checker::added_security_requirement_check::scopes_value;
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::DiffResult;
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::OperationDiff;
+use crate::visitor::DiffVisitor;
+
+pub struct AddedSecurityScopeCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for AddedSecurityScopeCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        operation_diff_result: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(operation) = operation_diff_result.get() else {
+            return false;
+        };
+
+        let Some(requirements) = operation.security.get() else {
+            return false;
+        };
+
+        let security_pointer =
+            pointer.add_component(&operation.security, Some("security"), None);
+
+        // Requirements are matched by their schemes, not by position
+        for (idx, requirement) in requirements.positioned() {
+            if !requirement.is_updated() {
+                continue;
+            }
+            let Some(schemes) = requirement.get() else {
+                continue;
+            };
+
+            let requirement_pointer =
+                security_pointer.add(requirement, idx.to_string(), None);
+
+            for (scheme, scopes) in schemes.iter() {
+                let has_added_scope = scopes.is_updated()
+                    && scopes.get().is_some_and(|scopes| {
+                        scopes.iter().any(|scope| scope.is_added())
+                    });
+
+                if has_added_scope {
+                    self.pointers.borrow_mut().push((
+                        requirement_pointer.add(scopes, scheme, None),
+                        scopes_value(scopes, false),
+                        scopes_value(scopes, true),
+                    ));
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl Default for AddedSecurityScopeCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for AddedSecurityScopeCheck {
+    fn id(&self) -> &'static str {
+        "added-security-scope"
+    }
+
+    fn description(&self) -> &'static str {
+        "Scope added to security requirement"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::added_security_scope_check::AddedSecurityScopeCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_added_security_scope_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = AddedSecurityScopeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/security/0/oauth",
+        );
+        assert_eq!(issues[0].old_value, Some(json!(["read"])));
+        assert_eq!(issues[0].new_value, Some(json!(["read", "write"])));
+    }
+}
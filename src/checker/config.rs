@@ -0,0 +1,174 @@
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::checker::{Severity, ValidationIssue};
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// Settings of a single rule
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleConfig {
+    /// Overrides `ValidationConfig::default_enabled` for the rule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Severity set on every issue of the rule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// Rule-specific options, unknown ones are ignored. Options apply to a
+    /// single run, missing ones take their default value.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub options: Map<String, Value>,
+    /// Globs of issue paths the rule does not report
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored_paths: Vec<String>,
+}
+
+/// Rule configuration, usually loaded from a JSON file
+///
+/// Paths are matched in the `PathPointer::get_json_pointer` form, where `/`
+/// inside a segment is escaped as `~1`. `*` matches within a single segment
+/// and `**` matches across segments, e.g. `/paths/*/delete` or
+/// `/paths/~1internal~1*/**`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationConfig {
+    /// Whether rules missing from `rules` run
+    #[serde(default = "enabled_by_default")]
+    pub default_enabled: bool,
+    #[serde(default)]
+    pub rules: IndexMap<String, RuleConfig>,
+    /// Globs of issue paths no rule reports
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored_paths: Vec<String>,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            default_enabled: true,
+            rules: IndexMap::new(),
+            ignored_paths: vec![],
+        }
+    }
+}
+
+impl ValidationConfig {
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(content)
+    }
+
+    pub fn is_enabled(&self, rule_id: &str) -> bool {
+        self.rules
+            .get(rule_id)
+            .and_then(|rule| rule.enabled)
+            .unwrap_or(self.default_enabled)
+    }
+
+    pub fn options(&self, rule_id: &str) -> Option<&Map<String, Value>> {
+        self.rules.get(rule_id).map(|rule| &rule.options)
+    }
+
+    /// Ids of configured rules missing from `rules`
+    pub fn unknown_rules<'c>(&'c self, rules: &[&str]) -> Vec<&'c str> {
+        self.rules
+            .keys()
+            .map(String::as_str)
+            .filter(|id| !rules.contains(id))
+            .collect()
+    }
+
+    /// Drops ignored issues and applies severity overrides
+    pub fn apply(&self, issues: Vec<ValidationIssue>) -> Vec<ValidationIssue> {
+        let global = compile_globs(&self.ignored_paths);
+        let rules: IndexMap<_, _> = self
+            .rules
+            .iter()
+            .map(|(id, rule)| {
+                (id.as_str(), compile_globs(&rule.ignored_paths))
+            })
+            .collect();
+
+        issues
+            .into_iter()
+            .filter(|issue| {
+                let path = issue.path.get_json_pointer();
+                let rule_globs = rules.get(issue.kind.as_ref());
+                !global
+                    .iter()
+                    .chain(rule_globs.into_iter().flatten())
+                    .any(|glob| glob.is_match(&path))
+            })
+            .map(|issue| {
+                match self
+                    .rules
+                    .get(issue.kind.as_ref())
+                    .and_then(|rule| rule.severity)
+                {
+                    Some(severity) => issue.with_severity(severity),
+                    None => issue,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Anchored regex for a path glob
+fn compile_glob(glob: &str) -> Regex {
+    let mut pattern = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                pattern.push_str(".*");
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            char => pattern.push_str(&regex::escape(&char.to_string())),
+        }
+    }
+    pattern.push('$');
+
+    Regex::new(&pattern).expect("escaped glob is a valid regex")
+}
+
+fn compile_globs(globs: &[String]) -> Vec<Regex> {
+    globs.iter().map(|glob| compile_glob(glob)).collect()
+}
+
+/// Boolean rule option holding the value of the current run, back to the
+/// rule's default unless the run's options set it
+#[derive(Debug, Clone, Copy)]
+pub struct BoolOption {
+    name: &'static str,
+    default: bool,
+    value: bool,
+}
+
+impl BoolOption {
+    pub fn new(name: &'static str, default: bool) -> Self {
+        Self {
+            name,
+            default,
+            value: default,
+        }
+    }
+
+    /// Takes the value from the options, the default when missing or not
+    /// a boolean
+    pub fn configure(&mut self, options: &Map<String, Value>) {
+        self.value = options
+            .get(self.name)
+            .and_then(Value::as_bool)
+            .unwrap_or(self.default);
+    }
+
+    pub fn get(&self) -> bool {
+        self.value
+    }
+}
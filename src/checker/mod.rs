@@ -1,41 +1,236 @@
+pub mod added_required_all_of_member_check;
 pub mod added_required_body_property_check;
 pub mod added_required_parameter_check;
 pub mod added_required_request_body_check;
+pub mod added_schema_variant_check;
+pub mod added_security_requirement_check;
+pub mod added_security_scope_check;
+pub mod config;
+pub mod registry;
 pub mod removed_media_type_check;
 pub mod removed_operation_check;
+pub mod removed_response_code_check;
+pub mod removed_response_header_check;
 pub mod removed_response_property_check;
 pub mod removed_schema_enum_value_check;
+pub mod removed_schema_variant_check;
+pub mod removed_security_scheme_check;
+pub mod replaced_success_response_code_check;
+pub mod suppression;
+pub mod updated_additional_properties_check;
+pub mod updated_discriminator_check;
+pub mod updated_oauth_flow_url_check;
+pub mod updated_parameter_type_check;
+pub mod updated_required_body_property_check;
+pub mod updated_schema_access_check;
+pub mod updated_schema_constraint_check;
+pub mod updated_schema_default_check;
+pub mod updated_schema_enum_check;
+pub mod updated_schema_format_check;
+pub mod updated_schema_nullable_check;
 pub mod updated_schema_type_check;
+pub mod updated_security_scheme_check;
 
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::checker::updated_schema_type_check::schema_types;
+use crate::core::{
+    DiffResult, EitherDiff, MayBeRefCoreDiff, ReferenceDescriptor, VecDiff,
+};
 use crate::path_pointer::PathPointer;
-use crate::schema_diff::HttpSchemaDiff;
+use crate::schema_diff::{HttpSchemaDiff, MayBeRefDiff, SchemaDiff};
+
+use crate::checker::registry::CheckerRegistry;
+use crate::checker::suppression::Suppression;
+use crate::visitor::DiffVisitor;
+
+/// Pointer to the reported node with its old and new values
+pub type ValueChange = (PathPointer, Option<Value>, Option<Value>);
 
-use crate::visitor::{DiffVisitor, MergedVisitor};
+/// Schema type of one side, a string unless it is a list of several
+pub(crate) fn types_value(
+    r#type: &DiffResult<EitherDiff<String, VecDiff<String, impl Sized>>>,
+    target: bool,
+) -> Option<Value> {
+    let types = schema_types(r#type, target)?;
+    Some(match types.as_slice() {
+        [single] => Value::from(*single),
+        types => Value::from(types.to_vec()),
+    })
+}
+
+/// Keywords telling a schema apart on one side, used as the value of
+/// issues about whole schemas
+pub(crate) fn schema_value(schema: &SchemaDiff, target: bool) -> Value {
+    let mut map = Map::new();
+
+    if let Some(title) = schema.title.get_side(target) {
+        map.insert("title".into(), Value::from(title.as_str()));
+    }
+    if let Some(types) = types_value(&schema.r#type, target) {
+        map.insert("type".into(), types);
+    }
+    if let Some(format) = schema.format.get_side(target) {
+        map.insert("format".into(), Value::from(format.as_str()));
+    }
+    if let Some(values) = schema.r#enum.get_side(target) {
+        let values = values.side(target).into_iter().cloned().collect();
+        map.insert("enum".into(), Value::Array(values));
+    }
+    if let Some(required) = schema.required.get_side(target) {
+        let required: Vec<_> = required
+            .side(target)
+            .into_iter()
+            .map(String::as_str)
+            .collect();
+        map.insert("required".into(), Value::from(required));
+    }
 
-use crate::checker::added_required_body_property_check::AddedRequiredBodyPropertyCheck;
-use crate::checker::added_required_parameter_check::AddedRequiredParameterCheck;
-use crate::checker::added_required_request_body_check::AddedRequiredRequestBodyCheck;
-use crate::checker::removed_media_type_check::RemovedMediaTypeCheck;
-use crate::checker::removed_operation_check::RemovedOperationCheck;
-use crate::checker::removed_response_property_check::RemovedResponsePropertyCheck;
-use crate::checker::removed_schema_enum_value_check::RemovedSchemaEnumValueCheck;
-use crate::checker::updated_schema_type_check::UpdatedSchemaTypeCheck;
+    Value::Object(map)
+}
 
-#[derive(Debug)]
+/// Reference of a schema, or its keywords when inline
+pub(crate) fn schema_ref_value(
+    schema: &MayBeRefDiff<SchemaDiff>,
+    target: bool,
+) -> Option<Value> {
+    match schema {
+        MayBeRefCoreDiff::Ref(reference) => {
+            Some(Value::from(reference.reference()))
+        }
+        MayBeRefCoreDiff::Value(schema) => {
+            schema.get().map(|schema| schema_value(schema, target))
+        }
+    }
+}
+
+/// How much attention an issue needs
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Worth knowing, consumers are not affected
+    Info,
+    /// Consumers may be affected depending on how they use the API
+    Warning,
+    /// Consumers relying on the previous contract break
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ValidationIssue {
     pub path: PathPointer,
+    /// Whether the issue is an error, kept in sync with `severity`
     pub breaking: bool,
-    pub kind: &'static str,
+    /// Stable id of the rule which reported the issue
+    pub kind: Cow<'static, str>,
+    pub severity: Severity,
+    /// Human-readable explanation, rendered by `validate`
+    #[serde(default)]
+    pub message: String,
+    /// Value on the source side, when the rule reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<Value>,
+    /// Value on the target side, when the rule reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<Value>,
+    /// Set when the issue is accepted, suppressed issues are still reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppressed: Option<Suppression>,
 }
 
 impl ValidationIssue {
     pub fn new(path: PathPointer, kind: &'static str, breaking: bool) -> Self {
         Self {
             path,
-            kind,
+            kind: Cow::Borrowed(kind),
             breaking,
+            severity: if breaking {
+                Severity::Error
+            } else {
+                Severity::Warning
+            },
+            message: String::new(),
+            old_value: None,
+            new_value: None,
+            suppressed: None,
+        }
+    }
+
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed.is_some()
+    }
+
+    pub fn with_suppression(mut self, suppression: Suppression) -> Self {
+        self.suppressed = Some(suppression);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self.breaking = severity == Severity::Error;
+        self
+    }
+
+    pub fn with_values(
+        mut self,
+        old_value: Option<Value>,
+        new_value: Option<Value>,
+    ) -> Self {
+        self.old_value = old_value;
+        self.new_value = new_value;
+        self
+    }
+
+    /// Renders the message from the rule description and the values
+    pub fn with_message(mut self, description: &str) -> Self {
+        fn display(value: &Value) -> String {
+            match value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            }
+        }
+
+        let old_value = self.old_value.as_ref().map(display);
+        let new_value = self.new_value.as_ref().map(display);
+        self.message = match (old_value, new_value) {
+            (Some(old), Some(new)) => {
+                format!("{description}: `{old}` changed to `{new}`")
+            }
+            (Some(old), None) => format!("{description}: `{old}` removed"),
+            (None, Some(new)) => format!("{description}: `{new}` added"),
+            (None, None) => description.to_string(),
+        };
+        self
+    }
+}
+
+/// Issues stored next to a diff, reloadable without validating again
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Version of the JSON shape of issues and pointers
+    pub version: String,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub const VERSION: &'static str = "1";
+
+    pub fn new(issues: Vec<ValidationIssue>) -> Self {
+        Self {
+            version: Self::VERSION.to_owned(),
+            issues,
         }
     }
+
+    pub fn is_current_version(&self) -> bool {
+        self.version == Self::VERSION
+    }
 }
 
 pub trait HasBreakingChange {
@@ -44,72 +239,196 @@ pub trait HasBreakingChange {
 
 impl HasBreakingChange for &[ValidationIssue] {
     fn has_breaking_changes(&self) -> bool {
-        self.iter().any(|x| x.breaking)
+        self.iter().any(|x| x.breaking && !x.is_suppressed())
     }
 }
 
 impl HasBreakingChange for &[&ValidationIssue] {
     fn has_breaking_changes(&self) -> bool {
-        self.iter().any(|x| x.breaking)
+        self.iter().any(|x| x.breaking && !x.is_suppressed())
     }
 }
 
-trait ValidationIssuer<'s> {
+/// Rule reporting issues found while its visitor walks a diff
+pub trait ValidationIssuer<'s> {
+    /// Stable id, used to select the rule and set as issue kind
     fn id(&self) -> &'static str;
+    /// Short sentence telling what the rule reports
+    fn description(&self) -> &'static str;
     fn visitor(&self) -> &dyn DiffVisitor<'s>;
+    /// Issues collected since the last call
     fn issues(&self) -> Option<Vec<ValidationIssue>>;
+    /// Applies rule options of `ValidationConfig`, replacing the options of
+    /// the previous call. An empty map restores the defaults.
+    fn configure(&mut self, _options: &Map<String, Value>) {}
 }
 
+/// Runs the built-in rules with the given ids, or all of them for `"*"`
 pub fn validate(
     diff: &HttpSchemaDiff,
     checkers: &[&str],
 ) -> Vec<ValidationIssue> {
-    let removed_operation = Box::<RemovedOperationCheck>::default();
-    let removed_media_type = Box::<RemovedMediaTypeCheck>::default();
-    let changed_schema_type = Box::<UpdatedSchemaTypeCheck>::default();
-    let removed_schema_enum_value =
-        Box::<RemovedSchemaEnumValueCheck>::default();
-    let added_required_parameter =
-        Box::<AddedRequiredParameterCheck>::default();
-    let removed_response_property =
-        Box::<RemovedResponsePropertyCheck>::default();
-    let added_required_request_body =
-        Box::<AddedRequiredRequestBodyCheck>::default();
-    let added_required_body_property =
-        Box::<AddedRequiredBodyPropertyCheck>::default();
-
-    let available_issuers: Vec<&dyn ValidationIssuer> = vec![
-        &*removed_operation,
-        &*removed_media_type,
-        &*changed_schema_type,
-        &*added_required_parameter,
-        &*removed_response_property,
-        &*removed_schema_enum_value,
-        &*added_required_request_body,
-        &*added_required_body_property,
-    ];
-
-    let issuers: Vec<_> = if checkers.contains(&"*") {
-        available_issuers
-    } else {
-        available_issuers
-            .into_iter()
-            .filter(|issuer| checkers.contains(&issuer.id()))
-            .collect()
+    CheckerRegistry::builtin().validate(diff, checkers)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::{
+        validate, Severity, ValidationIssue, ValidationReport,
     };
+    use crate::diff_result_type::DiffResultType;
+    use crate::get_schema_diff;
+    use crate::path_pointer::{PathPointer, PathPointerScope};
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+    use crate::schemas::openapi310::schema::OpenApi310;
 
-    let visitors: Vec<_> = issuers.iter().map(|v| v.visitor()).collect();
+    #[test]
+    fn test_webhooks_validation() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/webhooks/schema-with-webhooks.json"
+            ))
+            .unwrap()
+            .into();
 
-    {
-        let visitor = MergedVisitor::new(visitors.as_slice());
-        crate::visitor::dispatch_visitor(diff, &visitor);
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/webhooks/schema-with-webhooks-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+        let issues = validate(diff.get().unwrap(), &["*"]);
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.kind.as_ref(), issue.path.get_path()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("removed-operation", "webhooks/petDeleted/post".to_string()),
+                (
+                    "removed-response-property",
+                    "webhooks/newPet/post/requestBody/content/\
+                     application/json/schema/properties/name"
+                        .to_string()
+                ),
+                (
+                    "added-required-body-property",
+                    "webhooks/newPet/post/responses/200/content/\
+                     application/json/schema/properties/receivedAt"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validation_messages() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-schema-constraint/schema.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-schema-constraint/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+        let issues =
+            validate(diff.get().unwrap(), &["updated-schema-max-length"]);
+
+        let issue = issues.first().unwrap();
+        assert_eq!(issue.kind, "updated-schema-max-length");
+        assert_eq!(issue.severity, Severity::Error);
+        assert_eq!(issue.old_value, Some(json!(50)));
+        assert_eq!(issue.new_value, Some(json!(20)));
+        assert_eq!(
+            issue.message,
+            "Maximum string length changed: `50` changed to `20`"
+        );
     }
 
-    let results: Vec<_> = issuers
-        .into_iter()
-        .filter_map(|v| v.issues())
-        .flatten()
-        .collect();
+    #[test]
+    fn test_validation_issue_json_shape() {
+        let path = PathPointer::new(
+            DiffResultType::Updated,
+            Some("paths"),
+            Some(PathPointerScope::Paths),
+        )
+        .add(
+            DiffResultType::Removed,
+            "/pets",
+            Some(PathPointerScope::Path),
+        )
+        .add_context(DiffResultType::Removed);
+
+        let issue = ValidationIssue::new(path, "removed-operation", true)
+            .with_values(Some(json!("GET")), None)
+            .with_message("Operation removed");
 
-    results
+        let value =
+            serde_json::to_value(ValidationReport::new(vec![issue])).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "version": "1",
+                "issues": [{
+                    "path": [
+                        {"kind": "updated", "path": "paths", "scope": "paths"},
+                        {"kind": "removed", "path": "/pets", "scope": "path"},
+                        {"kind": "removed"}
+                    ],
+                    "breaking": true,
+                    "kind": "removed-operation",
+                    "severity": "error",
+                    "message": "Operation removed: `GET` removed",
+                    "oldValue": "GET"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_validation_report_round_trip() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/webhooks/schema-with-webhooks.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/webhooks/schema-with-webhooks-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+        let report =
+            ValidationReport::new(validate(diff.get().unwrap(), &["*"]));
+        assert!(!report.issues.is_empty());
+
+        let content = serde_json::to_string(&report).unwrap();
+        let loaded: ValidationReport = serde_json::from_str(&content).unwrap();
+
+        assert!(loaded.is_current_version());
+        assert_eq!(loaded, report);
+        assert_eq!(
+            loaded.issues.first().unwrap().path.get_path(),
+            "webhooks/petDeleted/post"
+        );
+        assert!(loaded.issues.first().unwrap().path.is_removed());
+    }
 }
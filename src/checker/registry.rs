@@ -0,0 +1,392 @@
+use serde_json::Map;
+
+use crate::checker::config::ValidationConfig;
+use crate::checker::suppression::IgnoreExtensionVisitor;
+use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::error::ConfigError;
+use crate::schema_diff::HttpSchemaDiff;
+use crate::visitor::{DiffVisitor, MergedVisitor};
+
+use crate::checker::added_required_all_of_member_check::AddedRequiredAllOfMemberCheck;
+use crate::checker::added_required_body_property_check::AddedRequiredBodyPropertyCheck;
+use crate::checker::added_required_parameter_check::AddedRequiredParameterCheck;
+use crate::checker::added_required_request_body_check::AddedRequiredRequestBodyCheck;
+use crate::checker::added_schema_variant_check::AddedSchemaVariantCheck;
+use crate::checker::added_security_requirement_check::AddedSecurityRequirementCheck;
+use crate::checker::added_security_scope_check::AddedSecurityScopeCheck;
+use crate::checker::removed_media_type_check::RemovedMediaTypeCheck;
+use crate::checker::removed_operation_check::RemovedOperationCheck;
+use crate::checker::removed_response_code_check::RemovedResponseCodeCheck;
+use crate::checker::removed_response_header_check::RemovedResponseHeaderCheck;
+use crate::checker::removed_response_property_check::RemovedResponsePropertyCheck;
+use crate::checker::removed_schema_enum_value_check::RemovedSchemaEnumValueCheck;
+use crate::checker::removed_schema_variant_check::RemovedSchemaVariantCheck;
+use crate::checker::removed_security_scheme_check::RemovedSecuritySchemeCheck;
+use crate::checker::replaced_success_response_code_check::ReplacedSuccessResponseCodeCheck;
+use crate::checker::updated_additional_properties_check::UpdatedAdditionalPropertiesCheck;
+use crate::checker::updated_discriminator_check::UpdatedDiscriminatorCheck;
+use crate::checker::updated_oauth_flow_url_check::UpdatedOAuthFlowUrlCheck;
+use crate::checker::updated_parameter_type_check::UpdatedParameterTypeCheck;
+use crate::checker::updated_required_body_property_check::UpdatedRequiredBodyPropertyCheck;
+use crate::checker::updated_schema_access_check::UpdatedSchemaAccessCheck;
+use crate::checker::updated_schema_constraint_check::{
+    SchemaConstraint, UpdatedSchemaConstraintCheck,
+};
+use crate::checker::updated_schema_default_check::UpdatedSchemaDefaultCheck;
+use crate::checker::updated_schema_enum_check::UpdatedSchemaEnumCheck;
+use crate::checker::updated_schema_format_check::UpdatedSchemaFormatCheck;
+use crate::checker::updated_schema_nullable_check::UpdatedSchemaNullableCheck;
+use crate::checker::updated_schema_type_check::UpdatedSchemaTypeCheck;
+use crate::checker::updated_security_scheme_check::UpdatedSecuritySchemeCheck;
+
+/// Rule usable with diffs of any lifetime
+pub type BoxedValidationIssuer = Box<dyn for<'s> ValidationIssuer<'s>>;
+
+/// Ordered set of rules, run together in a single pass over a diff
+pub struct CheckerRegistry {
+    issuers: Vec<BoxedValidationIssuer>,
+}
+
+impl CheckerRegistry {
+    /// Registry without any rules
+    pub fn new() -> Self {
+        Self { issuers: vec![] }
+    }
+
+    /// Registry with all rules shipped with the library
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        registry.register(RemovedOperationCheck::default());
+        registry.register(RemovedMediaTypeCheck::default());
+        registry.register(UpdatedSchemaTypeCheck::default());
+        registry.register(AddedRequiredParameterCheck::default());
+        registry.register(RemovedResponsePropertyCheck::default());
+        registry.register(RemovedSchemaEnumValueCheck::default());
+        registry.register(AddedRequiredRequestBodyCheck::default());
+        registry.register(AddedRequiredBodyPropertyCheck::default());
+        registry.register(AddedSecurityRequirementCheck::default());
+        registry.register(AddedSecurityScopeCheck::default());
+        registry.register(RemovedSecuritySchemeCheck::default());
+        registry.register(UpdatedSecuritySchemeCheck::default());
+        registry.register(UpdatedOAuthFlowUrlCheck::default());
+        registry.register(UpdatedParameterTypeCheck::default());
+        registry.register(UpdatedRequiredBodyPropertyCheck::default());
+        registry.register(RemovedResponseCodeCheck::default());
+        registry.register(RemovedResponseHeaderCheck::default());
+        registry.register(ReplacedSuccessResponseCodeCheck::default());
+        registry.register(RemovedSchemaVariantCheck::default());
+        registry.register(AddedSchemaVariantCheck::default());
+        registry.register(AddedRequiredAllOfMemberCheck::default());
+        registry.register(UpdatedDiscriminatorCheck::default());
+        registry.register(UpdatedSchemaNullableCheck::default());
+        registry.register(UpdatedSchemaAccessCheck::default());
+        registry.register(UpdatedAdditionalPropertiesCheck::default());
+        registry.register(UpdatedSchemaDefaultCheck::default());
+        registry.register(UpdatedSchemaFormatCheck::default());
+        registry.register(UpdatedSchemaEnumCheck::default());
+        for constraint in SchemaConstraint::ALL {
+            registry.register(UpdatedSchemaConstraintCheck::new(constraint));
+        }
+
+        registry
+    }
+
+    /// Adds a rule, replacing the registered rule with the same id
+    pub fn register<I>(&mut self, issuer: I) -> &mut Self
+    where
+        I: for<'s> ValidationIssuer<'s> + 'static,
+    {
+        let issuer: BoxedValidationIssuer = Box::new(issuer);
+        match self.issuers.iter().position(|v| v.id() == issuer.id()) {
+            Some(idx) => self.issuers[idx] = issuer,
+            None => self.issuers.push(issuer),
+        }
+        self
+    }
+
+    /// Ids and descriptions of registered rules, in run order
+    pub fn rules(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &'static str)> + '_ {
+        self.issuers
+            .iter()
+            .map(|issuer| (issuer.id(), issuer.description()))
+    }
+
+    /// Runs the rules with the given ids, or all of them for `"*"`
+    ///
+    /// Issues inside targets marked with `x-schemadoc-ignore` are reported
+    /// as suppressed.
+    pub fn validate(
+        &self,
+        diff: &HttpSchemaDiff,
+        checkers: &[&str],
+    ) -> Vec<ValidationIssue> {
+        let issuers: Vec<_> = self
+            .issuers
+            .iter()
+            .filter(|issuer| {
+                checkers.contains(&"*") || checkers.contains(&issuer.id())
+            })
+            .collect();
+
+        let ignore = IgnoreExtensionVisitor::default();
+
+        let mut visitors: Vec<&dyn DiffVisitor> =
+            issuers.iter().map(|v| v.visitor()).collect();
+        visitors.push(&ignore);
+
+        {
+            let visitor = MergedVisitor::new(visitors.as_slice());
+            crate::visitor::dispatch_visitor(diff, &visitor);
+        }
+
+        let issues = issuers
+            .into_iter()
+            .flat_map(|v| {
+                v.issues()
+                    .into_iter()
+                    .flatten()
+                    .map(|issue| issue.with_message(v.description()))
+            })
+            .collect();
+
+        ignore.apply(issues)
+    }
+
+    /// Runs the rules enabled by the config with its options, which only
+    /// apply to this run
+    pub fn validate_with_config(
+        &mut self,
+        diff: &HttpSchemaDiff,
+        config: &ValidationConfig,
+    ) -> Result<Vec<ValidationIssue>, ConfigError> {
+        let rules: Vec<_> = self.rules().map(|(id, _)| id).collect();
+        let unknown = config.unknown_rules(&rules);
+        if !unknown.is_empty() {
+            return Err(ConfigError::UnknownRules(
+                unknown.into_iter().map(String::from).collect(),
+            ));
+        }
+
+        let defaults = Map::new();
+        for issuer in self.issuers.iter_mut() {
+            issuer.configure(config.options(issuer.id()).unwrap_or(&defaults));
+        }
+
+        let enabled: Vec<_> = rules
+            .into_iter()
+            .filter(|id| config.is_enabled(id))
+            .collect();
+        let issues = config.apply(self.validate(diff, &enabled));
+
+        for issuer in self.issuers.iter_mut() {
+            issuer.configure(&defaults);
+        }
+
+        Ok(issues)
+    }
+}
+
+impl Default for CheckerRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use crate::checker::config::ValidationConfig;
+    use crate::checker::registry::CheckerRegistry;
+    use crate::checker::{Severity, ValidationIssue, ValidationIssuer};
+    use crate::core::DiffResult;
+    use crate::get_schema_diff;
+    use crate::path_pointer::PathPointer;
+    use crate::schema::HttpSchema;
+    use crate::schema_diff::OperationDiff;
+    use crate::schemas::openapi303::schema::OpenApi303;
+    use crate::schemas::openapi310::schema::OpenApi310;
+    use crate::visitor::DiffVisitor;
+
+    /// Company rule flagging every updated operation
+    #[derive(Default)]
+    struct UpdatedOperationCheck {
+        pointers: RefCell<Vec<PathPointer>>,
+    }
+
+    impl<'s> DiffVisitor<'s> for UpdatedOperationCheck {
+        fn visit_operation(
+            &self,
+            pointer: &PathPointer,
+            _: &str,
+            _: &'s DiffResult<OperationDiff>,
+        ) -> bool {
+            if pointer.is_updated() {
+                self.pointers.borrow_mut().push(pointer.clone());
+            }
+            false
+        }
+    }
+
+    impl<'s> ValidationIssuer<'s> for UpdatedOperationCheck {
+        fn id(&self) -> &'static str {
+            "x-updated-operation"
+        }
+
+        fn description(&self) -> &'static str {
+            "Operation updated"
+        }
+
+        fn visitor(&self) -> &dyn DiffVisitor<'s> {
+            self
+        }
+
+        fn issues(&self) -> Option<Vec<ValidationIssue>> {
+            let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+            Some(
+                pointers
+                    .into_iter()
+                    .map(|path| ValidationIssue::new(path, self.id(), false))
+                    .collect(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/webhooks/schema-with-webhooks.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/webhooks/schema-with-webhooks-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let mut registry = CheckerRegistry::builtin();
+        let builtin_count = registry.rules().count();
+        registry.register(UpdatedOperationCheck::default());
+
+        assert_eq!(registry.rules().count(), builtin_count + 1);
+        assert_eq!(
+            registry.rules().last(),
+            Some(("x-updated-operation", "Operation updated"))
+        );
+
+        // Built-in and custom rules run in the same pass
+        let issues = registry.validate(diff.get().unwrap(), &["*"]);
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.kind.as_ref(), issue.path.get_path()))
+            .collect();
+        assert!(found.contains(&(
+            "removed-operation",
+            "webhooks/petDeleted/post".to_string()
+        )));
+        assert!(found.contains(&(
+            "x-updated-operation",
+            "webhooks/newPet/post".to_string()
+        )));
+
+        // Registering the same id again replaces the rule
+        registry.register(UpdatedOperationCheck::default());
+        assert_eq!(registry.rules().count(), builtin_count + 1);
+
+        let issues =
+            registry.validate(diff.get().unwrap(), &["x-updated-operation"]);
+        assert!(issues
+            .iter()
+            .all(|issue| issue.kind == "x-updated-operation"
+                && issue.message == "Operation updated"));
+    }
+
+    #[test]
+    fn test_validate_with_config() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/schema-enum/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/schema-enum/schema-altered.json"),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let config = ValidationConfig::from_json(
+            r#"{
+                "defaultEnabled": false,
+                "rules": {
+                    "updated-schema-enum": {
+                        "enabled": true,
+                        "options": { "responseAdditionsBreaking": false }
+                    },
+                    "removed-schema-enum-value": {
+                        "enabled": true,
+                        "severity": "info"
+                    }
+                },
+                "ignoredPaths": ["/paths/~1pets/*/requestBody/**"]
+            }"#,
+        )
+        .unwrap();
+
+        let mut registry = CheckerRegistry::builtin();
+        let issues = registry
+            .validate_with_config(diff.get().unwrap(), &config)
+            .unwrap();
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| {
+                (issue.kind.as_ref(), issue.path.get_path(), issue.severity)
+            })
+            .collect();
+
+        let response =
+            "paths//pets/post/responses/200/content/application/json/schema";
+        assert_eq!(
+            found,
+            vec![
+                // severity overridden
+                (
+                    "removed-schema-enum-value",
+                    "paths//pets/post/parameters/0/schema/enum".into(),
+                    Severity::Info
+                ),
+                // response additions configured as non-breaking
+                (
+                    "updated-schema-enum",
+                    format!("{response}/properties/status/enum"),
+                    Severity::Warning
+                ),
+            ]
+        );
+
+        // Options do not outlive the run
+        let issues =
+            registry.validate(diff.get().unwrap(), &["updated-schema-enum"]);
+        assert!(issues.iter().all(|issue| issue.breaking));
+
+        let config = ValidationConfig::from_json(
+            r#"{ "rules": { "updated-schema-enums": { "enabled": false } } }"#,
+        )
+        .unwrap();
+        let error = registry
+            .validate_with_config(diff.get().unwrap(), &config)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "unknown rules: updated-schema-enums");
+    }
+}
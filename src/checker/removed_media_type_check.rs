@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
 use crate::core::{DiffResult, MapDiff};
 use crate::path_pointer::PathPointer;
 use crate::schema_diff::{
@@ -10,7 +12,7 @@ use crate::schema_diff::{
 use crate::visitor::DiffVisitor;
 
 pub struct RemovedMediaTypeCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for RemovedMediaTypeCheck {
@@ -53,7 +55,12 @@ impl<'s> DiffVisitor<'s> for RemovedMediaTypeCheck {
         _: &'s DiffResult<MediaTypeDiff>,
     ) -> bool {
         if pointer.is_removed() {
-            self.pointers.borrow_mut().push(pointer.clone());
+            let media_type = pointer.segment().map(Value::from);
+            self.pointers.borrow_mut().push((
+                pointer.clone(),
+                media_type,
+                None,
+            ));
             return false;
         }
 
@@ -74,6 +81,10 @@ impl<'s> ValidationIssuer<'s> for RemovedMediaTypeCheck {
         "removed-media-type"
     }
 
+    fn description(&self) -> &'static str {
+        "Media type removed"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -83,7 +94,10 @@ impl<'s> ValidationIssuer<'s> for RemovedMediaTypeCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -92,6 +106,8 @@ impl<'s> ValidationIssuer<'s> for RemovedMediaTypeCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::removed_media_type_check::RemovedMediaTypeCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -121,9 +137,11 @@ mod tests {
 
         assert_eq!(issues.len(), 2);
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
+            issues.first().unwrap().path.get_path(),
             "paths//test/put/requestBody/content/text/plain",
         );
+        assert_eq!(issues[0].old_value, Some(json!("text/plain")));
+        assert_eq!(issues[0].new_value, None);
         assert_eq!(
             issues.get(1).unwrap().path.get_path(),
             "paths//test/put/responses/200/content/text/plain",
@@ -1,25 +1,39 @@
 use std::cell::RefCell;
 
+use serde_json::Value;
+
 use crate::core::DiffResult;
-use crate::path_pointer::PathPointer;
+use crate::path_pointer::{PathPointer, PathPointerScope};
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
 use crate::schema_diff::OperationDiff;
 use crate::visitor::DiffVisitor;
 
 pub struct RemovedOperationCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for RemovedOperationCheck {
     fn visit_operation(
         &self,
         pointer: &PathPointer,
-        _method: &str,
+        method: &str,
         operation_diff_result: &'s DiffResult<OperationDiff>,
     ) -> bool {
         if let DiffResult::Removed(_) = operation_diff_result {
-            self.pointers.borrow_mut().push(pointer.clone())
+            let path = pointer
+                .components
+                .iter()
+                .rev()
+                .find(|c| c.scope == Some(PathPointerScope::Path))
+                .and_then(|c| c.path.as_deref())
+                .unwrap_or_default();
+            let operation = format!("{} {path}", method.to_uppercase());
+            self.pointers.borrow_mut().push((
+                pointer.clone(),
+                Some(Value::from(operation)),
+                None,
+            ))
         }
         false
     }
@@ -38,6 +52,10 @@ impl<'s> ValidationIssuer<'s> for RemovedOperationCheck {
         "removed-operation"
     }
 
+    fn description(&self) -> &'static str {
+        "Operation removed"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -47,7 +65,10 @@ impl<'s> ValidationIssuer<'s> for RemovedOperationCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -56,6 +77,8 @@ impl<'s> ValidationIssuer<'s> for RemovedOperationCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::removed_operation_check::RemovedOperationCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -85,11 +108,14 @@ mod tests {
 
         assert_eq!(issues.len(), 2);
         // replaced with `delete`
-        assert_eq!(issues.get(0).unwrap().path.get_path(), "paths//test/put",);
+        assert_eq!(issues.first().unwrap().path.get_path(), "paths//test/put",);
+        assert_eq!(issues[0].old_value, Some(json!("PUT /test")));
+        assert_eq!(issues[0].new_value, None);
         //removed from paths
         assert_eq!(
             issues.get(1).unwrap().path.get_path(),
             "paths//test2/post",
         );
+        assert_eq!(issues[1].old_value, Some(json!("POST /test2")));
     }
 }
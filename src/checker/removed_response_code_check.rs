@@ -0,0 +1,162 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope};
+use crate::schema_diff::{MayBeRefDiff, OperationDiff, ResponseDiff};
+use crate::visitor::DiffVisitor;
+
+pub(crate) fn is_success_code(code: &str) -> bool {
+    code.starts_with('2')
+}
+
+/// Whether some success code was removed while another one was added
+pub(crate) fn is_success_code_replaced(
+    responses: &MapDiff<MayBeRefDiff<ResponseDiff>>,
+) -> bool {
+    let changed =
+        |check: fn(&DiffResult<MayBeRefDiff<ResponseDiff>>) -> bool| {
+            responses.iter().any(|(code, response)| {
+                is_success_code(code) && check(response)
+            })
+        };
+
+    changed(DiffResult::is_removed) && changed(DiffResult::is_added)
+}
+
+pub struct RemovedResponseCodeCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for RemovedResponseCodeCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        responses_diff_result: &'s DiffResult<
+            MapDiff<MayBeRefDiff<ResponseDiff>>,
+        >,
+    ) -> bool {
+        // Webhook responses are sent by the consumer
+        if !pointer.is_updated() || !pointer.is_response() {
+            return false;
+        }
+
+        let Some(responses) = responses_diff_result.get() else {
+            return false;
+        };
+
+        // Reported by `replaced-success-response-code`
+        let is_replaced = is_success_code_replaced(responses);
+
+        for (code, response) in responses.iter() {
+            if !response.is_removed() {
+                continue;
+            }
+            if is_replaced && is_success_code(code) {
+                continue;
+            }
+            self.pointers.borrow_mut().push((
+                pointer.add(
+                    response,
+                    code,
+                    Some(PathPointerScope::ResponseCode),
+                ),
+                Some(Value::from(code.as_str())),
+                None,
+            ));
+        }
+
+        false
+    }
+}
+
+impl Default for RemovedResponseCodeCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for RemovedResponseCodeCheck {
+    fn id(&self) -> &'static str {
+        "removed-response-code"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response code removed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::removed_response_code_check::RemovedResponseCodeCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_removed_response_code_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/responses/schema-with-responses.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/responses/schema-with-responses-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = RemovedResponseCodeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        // replaced `201` is reported by `replaced-success-response-code`
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/responses/404",
+        );
+        assert_eq!(issues[0].old_value, Some(json!("404")));
+        assert_eq!(issues[0].new_value, None);
+    }
+}
@@ -0,0 +1,164 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff};
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::{
+    HeaderDiff, MayBeRefDiff, OperationDiff, ResponseDiff,
+};
+use crate::visitor::DiffVisitor;
+
+pub struct RemovedResponseHeaderCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for RemovedResponseHeaderCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_response()
+    }
+
+    fn visit_response_ref(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MayBeRefDiff<ResponseDiff>>,
+    ) -> bool {
+        // Removed responses are reported by `removed-response-code`
+        pointer.is_updated()
+    }
+
+    fn visit_headers(
+        &self,
+        pointer: &PathPointer,
+        headers_diff_result: &'s DiffResult<MapDiff<MayBeRefDiff<HeaderDiff>>>,
+    ) -> bool {
+        // A removed map drops every header it had
+        if !pointer.is_updated() && !pointer.is_removed() {
+            return false;
+        }
+
+        let Some(headers) = headers_diff_result.get() else {
+            return false;
+        };
+
+        for (name, header) in headers.iter() {
+            if !header.is_removed() {
+                continue;
+            }
+
+            // Header names are case-insensitive
+            let is_renamed = headers.iter().any(|(other, header)| {
+                header.is_added() && other.eq_ignore_ascii_case(name)
+            });
+
+            if !is_renamed {
+                self.pointers.borrow_mut().push((
+                    pointer.add(header, name, None),
+                    Some(Value::from(name.as_str())),
+                    None,
+                ));
+            }
+        }
+
+        false
+    }
+}
+
+impl Default for RemovedResponseHeaderCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for RemovedResponseHeaderCheck {
+    fn id(&self) -> &'static str {
+        "removed-response-header"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response header removed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::removed_response_header_check::RemovedResponseHeaderCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_removed_response_header_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/responses/schema-with-responses.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/responses/schema-with-responses-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = RemovedResponseHeaderCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        // `ETag` renamed to `Etag` is not a removal
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/{id}/get/responses/200/headers/X-Rate-Limit",
+        );
+        assert_eq!(issues[0].old_value, Some(json!("X-Rate-Limit")));
+        assert_eq!(issues[0].new_value, None);
+        // the only header dropped along with the map
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "paths//pets/{id}/put/responses/204/headers/ETag",
+        );
+    }
+}
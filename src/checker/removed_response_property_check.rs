@@ -1,15 +1,18 @@
 use std::cell::RefCell;
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::checker::{
+    schema_value, ValidationIssue, ValidationIssuer, ValueChange,
+};
 use crate::core::{DiffResult, MapDiff};
 use crate::path_pointer::{PathPointer, PointerAncestor};
 use crate::schema_diff::{
-    MayBeRefDiff, MediaTypeDiff, OperationDiff, ResponseDiff, SchemaDiff,
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
 };
 use crate::visitor::DiffVisitor;
 
 pub struct RemovedResponsePropertyCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for RemovedResponsePropertyCheck {
@@ -27,7 +30,16 @@ impl<'s> DiffVisitor<'s> for RemovedResponsePropertyCheck {
         pointer: &PathPointer,
         _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
     ) -> bool {
-        pointer.is_updated()
+        pointer.is_updated() && pointer.is_response()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        // Webhook requests are received by the consumer
+        pointer.is_updated() && pointer.is_response()
     }
 
     fn visit_media_types(
@@ -49,7 +61,7 @@ impl<'s> DiffVisitor<'s> for RemovedResponsePropertyCheck {
     fn visit_schema(
         &self,
         pointer: &PathPointer,
-        _: &'s DiffResult<SchemaDiff>,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
     ) -> bool {
         if pointer.ancestor(PointerAncestor::schema()).is_removed() {
             return false;
@@ -59,7 +71,13 @@ impl<'s> DiffVisitor<'s> for RemovedResponsePropertyCheck {
             .ancestor(PointerAncestor::schema_property())
             .is_removed()
         {
-            self.pointers.borrow_mut().push(pointer.clone());
+            self.pointers.borrow_mut().push((
+                pointer.clone(),
+                schema_diff_result
+                    .get()
+                    .map(|schema| schema_value(schema, false)),
+                None,
+            ));
             return false;
         }
 
@@ -80,6 +98,10 @@ impl<'s> ValidationIssuer<'s> for RemovedResponsePropertyCheck {
         "removed-response-property"
     }
 
+    fn description(&self) -> &'static str {
+        "Property removed from response"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -89,7 +111,10 @@ impl<'s> ValidationIssuer<'s> for RemovedResponsePropertyCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -98,6 +123,8 @@ impl<'s> ValidationIssuer<'s> for RemovedResponsePropertyCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::removed_response_property_check::RemovedResponsePropertyCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -127,7 +154,7 @@ mod tests {
         assert_eq!(issues.len(), 3);
         // replaced with `shortname` property
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
+            issues.first().unwrap().path.get_path(),
             "paths//test/post/responses/200/content/application/json/schema/properties/description",
         );
         //removed
@@ -140,5 +167,17 @@ mod tests {
             issues.get(2).unwrap().path.get_path(),
             "paths//test/put/responses/200/content/application/json/schema/properties/id",
         );
+
+        let old_values: Vec<_> =
+            issues.iter().map(|issue| issue.old_value.clone()).collect();
+        assert_eq!(
+            old_values,
+            vec![
+                Some(json!({"type": "string"})),
+                Some(json!({"type": "boolean"})),
+                Some(json!({"type": "number"})),
+            ]
+        );
+        assert!(issues.iter().all(|issue| issue.new_value.is_none()));
     }
 }
@@ -1,9 +1,11 @@
 use std::cell::RefCell;
 
+use serde_json::Value;
+
 use crate::core::{DiffResult, MapDiff, VecDiff};
-use crate::path_pointer::PathPointer;
+use crate::path_pointer::{PathPointer, PointerDirection};
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
 use crate::schema_diff::{
     MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
     RequestBodyDiff, ResponseDiff, SchemaDiff,
@@ -11,7 +13,7 @@ use crate::schema_diff::{
 use crate::visitor::DiffVisitor;
 
 pub struct RemovedSchemaEnumValueCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for RemovedSchemaEnumValueCheck {
@@ -85,16 +87,24 @@ impl<'s> DiffVisitor<'s> for RemovedSchemaEnumValueCheck {
             return false;
         };
 
-        if schema.r#enum.is_updated() {
-            let has_removed = match schema.r#enum.get() {
-                None => false,
-                Some(values) => values.iter().any(|v| v.is_removed()),
+        // Responses dropping a value only narrow what consumers receive
+        let is_response =
+            pointer.direction() == Some(PointerDirection::Response);
+
+        if schema.r#enum.is_updated() && !is_response {
+            let Some(values) = schema.r#enum.get() else {
+                return true;
             };
-            if has_removed {
-                self.pointers.borrow_mut().push(pointer.add_component(
-                    &schema.r#enum,
-                    Some("enum"),
-                    None,
+            if values.iter().any(|v| v.is_removed()) {
+                let side = |target| {
+                    Value::Array(
+                        values.side(target).into_iter().cloned().collect(),
+                    )
+                };
+                self.pointers.borrow_mut().push((
+                    pointer.add_component(&schema.r#enum, Some("enum"), None),
+                    Some(side(false)),
+                    Some(side(true)),
                 ))
             }
         }
@@ -116,6 +126,10 @@ impl<'s> ValidationIssuer<'s> for RemovedSchemaEnumValueCheck {
         "removed-schema-enum-value"
     }
 
+    fn description(&self) -> &'static str {
+        "Value removed from enum"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -125,7 +139,10 @@ impl<'s> ValidationIssuer<'s> for RemovedSchemaEnumValueCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -134,6 +151,8 @@ impl<'s> ValidationIssuer<'s> for RemovedSchemaEnumValueCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::removed_schema_enum_value_check::RemovedSchemaEnumValueCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -160,14 +179,16 @@ mod tests {
         crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
         let issues = checker.issues().unwrap();
 
-        assert_eq!(issues.len(), 2);
+        // values removed from the response `prop1` are safe
+        assert_eq!(issues.len(), 1);
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
+            issues.first().unwrap().path.get_path(),
             "paths//test/post/requestBody/content/application/json/schema/enum",
         );
         assert_eq!(
-            issues.get(1).unwrap().path.get_path(),
-            "paths//test/post/responses/200/content/application/json/schema/properties/prop1/enum",
+            issues[0].old_value,
+            Some(json!(["enum1", "enum2", "enum3"]))
         );
+        assert_eq!(issues[0].new_value, Some(json!(["enum2", "enum3"])));
     }
 }
@@ -0,0 +1,248 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{schema_ref_value, ValidationIssue, ValidationIssuer};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+pub struct RemovedSchemaVariantCheck {
+    pointers: RefCell<Vec<(PathPointer, Option<Value>, bool)>>,
+}
+
+/// Branches of `oneOf`/`anyOf` compositions present on both sides, at
+/// their position in the source or target document
+pub(crate) fn composition_variants<'a>(
+    pointer: &PathPointer,
+    schema: &'a SchemaDiff,
+) -> Vec<(PathPointer, &'a DiffResult<MayBeRefDiff<SchemaDiff>>)> {
+    let compositions = [
+        (&schema.one_of, "oneOf", PathPointerScope::SchemaOneOf),
+        (&schema.any_of, "anyOf", PathPointerScope::SchemaAnyOf),
+    ];
+
+    let mut variants = Vec::new();
+
+    for (composition, keyword, scope) in compositions {
+        // Whole composition changes are not about single variants
+        if !composition.is_updated() {
+            continue;
+        }
+        let Some(branches) = composition.get() else {
+            continue;
+        };
+
+        let composition_pointer =
+            pointer.add(composition, keyword, Some(scope));
+        for (idx, branch) in branches.positioned() {
+            variants.push((
+                composition_pointer.add(branch, idx.to_string(), None),
+                branch,
+            ));
+        }
+    }
+
+    variants
+}
+
+impl<'s> DiffVisitor<'s> for RemovedSchemaVariantCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        // Consumers may still send the removed variant, while clients only
+        // stop receiving it
+        let breaking = !pointer.is_response();
+
+        for (variant_pointer, variant) in composition_variants(pointer, schema)
+        {
+            if variant.is_removed() {
+                let value = variant
+                    .get()
+                    .and_then(|variant| schema_ref_value(variant, false));
+                self.pointers.borrow_mut().push((
+                    variant_pointer,
+                    value,
+                    breaking,
+                ));
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for RemovedSchemaVariantCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for RemovedSchemaVariantCheck {
+    fn id(&self) -> &'static str {
+        "removed-schema-variant"
+    }
+
+    fn description(&self) -> &'static str {
+        "Variant removed from `oneOf`/`anyOf`"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, breaking)| {
+                ValidationIssue::new(path, self.id(), breaking)
+                    .with_values(old_value, None)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::removed_schema_variant_check::RemovedSchemaVariantCheck;
+    use crate::checker::{validate, ValidationIssuer};
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_removed_schema_variant_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/composition/schema-with-compositions.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/composition/schema-with-compositions-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = RemovedSchemaVariantCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.path.get_path(), issue.breaking))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                ("paths//pets/post/requestBody/content/application/json/schema/oneOf/1".to_owned(), true),
+                // inline branches are matched by content, not by position
+                ("paths//pets/post/requestBody/content/application/json/schema/properties/tag/oneOf/0".to_owned(), true),
+                // closest branches are paired before the remaining ones
+                ("paths//pets/post/requestBody/content/application/json/schema/properties/label/oneOf/1".to_owned(), true),
+                ("paths//pets/post/responses/200/content/application/json/schema/properties/toy/anyOf/1".to_owned(), false),
+            ]
+        );
+
+        let old_values: Vec<_> =
+            issues.iter().map(|issue| issue.old_value.clone()).collect();
+        assert_eq!(
+            old_values,
+            vec![
+                Some(json!("#/components/schemas/Dog")),
+                Some(json!({"type": "string"})),
+                Some(json!({"type": "integer"})),
+                Some(json!({"type": "integer"})),
+            ]
+        );
+
+        // the kept branch is not compared with the removed one
+        let issues = validate(diff.get().unwrap(), &["updated-schema-type"]);
+        assert!(issues.is_empty());
+    }
+}
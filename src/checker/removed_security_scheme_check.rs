@@ -0,0 +1,111 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::DiffResult;
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::SecuritySchemeDiff;
+use crate::visitor::DiffVisitor;
+
+pub struct RemovedSecuritySchemeCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for RemovedSecuritySchemeCheck {
+    fn visit_security_scheme(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        security_scheme_diff_result: &'s DiffResult<SecuritySchemeDiff>,
+    ) -> bool {
+        if pointer.is_removed() {
+            let r#type = security_scheme_diff_result
+                .get()
+                .and_then(|security_scheme| security_scheme.r#type.get())
+                .map(|r#type| Value::from(r#type.as_str()));
+            self.pointers
+                .borrow_mut()
+                .push((pointer.clone(), r#type, None))
+        }
+        false
+    }
+}
+
+impl Default for RemovedSecuritySchemeCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for RemovedSecuritySchemeCheck {
+    fn id(&self) -> &'static str {
+        "removed-security-scheme"
+    }
+
+    fn description(&self) -> &'static str {
+        "Security scheme removed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::removed_security_scheme_check::RemovedSecuritySchemeCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_removed_security_scheme_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = RemovedSecuritySchemeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "components/securitySchemes/basic",
+        );
+        assert_eq!(issues[0].old_value, Some(json!("http")));
+        assert_eq!(issues[0].new_value, None);
+    }
+}
@@ -0,0 +1,155 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::removed_response_code_check::{
+    is_success_code, is_success_code_replaced,
+};
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope};
+use crate::schema_diff::{MayBeRefDiff, OperationDiff, ResponseDiff};
+use crate::visitor::DiffVisitor;
+
+pub struct ReplacedSuccessResponseCodeCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for ReplacedSuccessResponseCodeCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        responses_diff_result: &'s DiffResult<
+            MapDiff<MayBeRefDiff<ResponseDiff>>,
+        >,
+    ) -> bool {
+        if !pointer.is_updated() || !pointer.is_response() {
+            return false;
+        }
+
+        let Some(responses) = responses_diff_result.get() else {
+            return false;
+        };
+
+        if !is_success_code_replaced(responses) {
+            return false;
+        }
+
+        let added: Vec<_> = responses
+            .iter()
+            .filter(|(code, response)| {
+                is_success_code(code) && response.is_added()
+            })
+            .map(|(code, _)| code.as_str())
+            .collect();
+        let added = match added.as_slice() {
+            [code] => Value::from(*code),
+            codes => Value::from(codes.to_vec()),
+        };
+
+        for (code, response) in responses.iter() {
+            if is_success_code(code) && response.is_removed() {
+                self.pointers.borrow_mut().push((
+                    pointer.add(
+                        response,
+                        code,
+                        Some(PathPointerScope::ResponseCode),
+                    ),
+                    Some(Value::from(code.as_str())),
+                    Some(added.clone()),
+                ));
+            }
+        }
+
+        false
+    }
+}
+
+impl Default for ReplacedSuccessResponseCodeCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for ReplacedSuccessResponseCodeCheck {
+    fn id(&self) -> &'static str {
+        "replaced-success-response-code"
+    }
+
+    fn description(&self) -> &'static str {
+        "Success response code replaced"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::replaced_success_response_code_check::ReplacedSuccessResponseCodeCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_replaced_success_response_code_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/responses/schema-with-responses.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/responses/schema-with-responses-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = ReplacedSuccessResponseCodeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        // `201` replaced with `200`
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/responses/201",
+        );
+        assert_eq!(issues[0].old_value, Some(json!("201")));
+        assert_eq!(issues[0].new_value, Some(json!("200")));
+    }
+}
@@ -0,0 +1,436 @@
+use std::cell::RefCell;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::checker::ValidationIssue;
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::{
+    CallbackDiff, HeaderDiff, MayBeRefDiff, MediaTypeDiff, OperationDiff,
+    ParameterDiff, RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+/// Extension on a target operation or schema suppressing issues reported
+/// inside it: `true` for all rules, or a rule id or a list of rule ids
+pub const IGNORE_EXTENSION: &str = "x-schemadoc-ignore";
+
+/// Why an issue does not count
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "by", rename_all = "camelCase")]
+pub enum Suppression {
+    /// `x-schemadoc-ignore` set on the target side
+    Extension,
+    /// Entry of the waiver file
+    Waiver {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+}
+
+/// Rule ids the extension value suppresses, `None` for all rules
+fn ignored_rules(value: &Value) -> Option<Option<Vec<String>>> {
+    match value {
+        Value::Bool(true) => Some(None),
+        Value::String(rule) => Some(Some(vec![rule.clone()])),
+        Value::Array(rules) => Some(Some(
+            rules
+                .iter()
+                .filter_map(|rule| rule.as_str().map(String::from))
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+/// Path and suppressed rule ids of a node ignored by the extension
+type IgnoredNode = (String, Option<Vec<String>>);
+
+/// Collects operations and schemas carrying `x-schemadoc-ignore`, run in
+/// the same pass as the rules
+#[derive(Default)]
+pub struct IgnoreExtensionVisitor {
+    ignored: RefCell<Vec<IgnoredNode>>,
+}
+
+impl IgnoreExtensionVisitor {
+    fn collect(
+        &self,
+        pointer: &PathPointer,
+        custom_fields: &DiffResult<MapDiff<Value>>,
+    ) {
+        let rules = custom_fields
+            .get_new()
+            .and_then(|fields| fields.get(IGNORE_EXTENSION))
+            .and_then(|value| value.get_new())
+            .and_then(ignored_rules);
+
+        if let Some(rules) = rules {
+            self.ignored.borrow_mut().push((pointer.get_path(), rules));
+        }
+    }
+
+    /// Marks issues inside ignored nodes, forgetting the collected nodes
+    pub fn apply(&self, issues: Vec<ValidationIssue>) -> Vec<ValidationIssue> {
+        let ignored = std::mem::take(&mut *self.ignored.borrow_mut());
+
+        issues
+            .into_iter()
+            .map(|issue| {
+                let path = issue.path.get_path();
+                let is_ignored = ignored.iter().any(|(node, rules)| {
+                    is_within(&path, node)
+                        && rules.as_ref().is_none_or(|rules| {
+                            rules.iter().any(|rule| *rule == issue.kind)
+                        })
+                });
+
+                if is_ignored && !issue.is_suppressed() {
+                    issue.with_suppression(Suppression::Extension)
+                } else {
+                    issue
+                }
+            })
+            .collect()
+    }
+}
+
+/// Whether the path is the node itself or lies below it
+fn is_within(path: &str, node: &str) -> bool {
+    path.strip_prefix(node)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+// The extension can only be read from nodes existing in the target
+impl<'s> DiffVisitor<'s> for IgnoreExtensionVisitor {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        operation_diff_result: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        if !pointer.is_upserted() {
+            return false;
+        }
+
+        if let Some(operation) = operation_diff_result.get() {
+            self.collect(pointer, &operation.custom_fields);
+        }
+
+        true
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_headers(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<HeaderDiff>>>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_upserted() {
+            return false;
+        }
+
+        if let Some(schema) = schema_diff_result.get() {
+            self.collect(pointer, &schema.custom_fields);
+        }
+
+        true
+    }
+
+    fn visit_callbacks(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<CallbackDiff>>,
+    ) -> bool {
+        pointer.is_upserted()
+    }
+}
+
+/// Accepted issue, usually a breaking change agreed with consumers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Waiver {
+    /// Id of the rule which reports the issue
+    pub rule: String,
+    /// Issue location as an RFC 6901 JSON pointer, see
+    /// `PathPointer::get_json_pointer`: `/` and `~` inside segments are
+    /// escaped as `~1` and `~0`, parameters are named `{in}:{name}`, e.g.
+    /// `/paths/~1pets~1{id}/delete` or
+    /// `/paths/~1pets/get/parameters/query:limit/schema`
+    pub pointer: String,
+    /// Last day the waiver applies, never expires when missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl Waiver {
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires.is_some_and(|expires| expires < today)
+    }
+
+    pub fn matches(&self, issue: &ValidationIssue) -> bool {
+        self.rule == issue.kind
+            && issue.path.get_json_pointer() == self.pointer
+    }
+}
+
+/// Waiver file, usually kept next to the schema it applies to
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Waivers {
+    pub waivers: Vec<Waiver>,
+}
+
+impl Waivers {
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(content)
+    }
+
+    /// Waivers which no longer apply and may be removed from the file
+    pub fn expired(&self, today: NaiveDate) -> Vec<&Waiver> {
+        self.waivers
+            .iter()
+            .filter(|waiver| waiver.is_expired(today))
+            .collect()
+    }
+
+    /// Marks issues waived by waivers not expired on `today`
+    pub fn apply(
+        &self,
+        issues: Vec<ValidationIssue>,
+        today: NaiveDate,
+    ) -> Vec<ValidationIssue> {
+        issues
+            .into_iter()
+            .map(|issue| {
+                if issue.is_suppressed() {
+                    return issue;
+                }
+
+                let waiver = self.waivers.iter().find(|waiver| {
+                    !waiver.is_expired(today) && waiver.matches(&issue)
+                });
+
+                match waiver {
+                    Some(waiver) => {
+                        issue.with_suppression(Suppression::Waiver {
+                            reason: waiver.reason.clone(),
+                        })
+                    }
+                    None => issue,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::checker::suppression::{Suppression, Waivers};
+    use crate::checker::{validate, HasBreakingChange};
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_suppression() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/suppression/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/suppression/schema-altered.json"),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let waivers = Waivers::from_json(
+            r#"{
+                "waivers": [
+                    {
+                        "rule": "removed-operation",
+                        "pointer": "/paths/~1pets~1{id}/delete",
+                        "expires": "2030-01-31",
+                        "reason": "Agreed with consumers"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let issues = validate(diff.get().unwrap(), &["*"]);
+        let today = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        let issues = waivers.apply(issues, today);
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.kind.as_ref(),
+                    issue.path.get_path(),
+                    issue.suppressed.clone(),
+                )
+            })
+            .collect();
+        let request =
+            "paths//pets/post/requestBody/content/application/json/schema";
+        let response =
+            "paths//pets/get/responses/200/content/application/json/schema";
+        let post =
+            "paths//pets/post/responses/200/content/application/json/schema";
+        assert_eq!(
+            found,
+            vec![
+                // waived until the end of January
+                (
+                    "removed-operation",
+                    "paths//pets/{id}/delete".into(),
+                    Some(Suppression::Waiver {
+                        reason: Some("Agreed with consumers".into())
+                    })
+                ),
+                // schema ignores all rules
+                (
+                    "removed-response-property",
+                    format!("{response}/properties/name"),
+                    Some(Suppression::Extension)
+                ),
+                // operation ignores `updated-schema-enum` only
+                (
+                    "removed-schema-enum-value",
+                    format!("{request}/properties/kind/enum"),
+                    None
+                ),
+                (
+                    "updated-schema-enum",
+                    format!("{post}/properties/status/enum"),
+                    Some(Suppression::Extension)
+                ),
+            ]
+        );
+
+        // Expired waivers are listed and no longer suppress issues
+        let today = NaiveDate::from_ymd_opt(2030, 2, 1).unwrap();
+        assert_eq!(waivers.expired(today).len(), 1);
+
+        let issues = waivers.apply(
+            validate(diff.get().unwrap(), &["removed-operation"]),
+            today,
+        );
+        assert_eq!(issues.len(), 1);
+        assert!(!issues[0].is_suppressed());
+        assert!(issues.as_slice().has_breaking_changes());
+
+        // Suppressed issues do not count as breaking
+        let issues = validate(diff.get().unwrap(), &["updated-schema-enum"]);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].breaking);
+        assert!(!issues.as_slice().has_breaking_changes());
+    }
+
+    #[test]
+    fn test_waiver_parameter_pointer() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/schema-enum/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/schema-enum/schema-altered.json"),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        // Parameters are addressed by location and name, not by position
+        let waivers = Waivers::from_json(
+            r#"{
+                "waivers": [
+                    {
+                        "rule": "removed-schema-enum-value",
+                        "pointer": "/paths/~1pets/post/parameters/query:sort/schema/enum"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let issues =
+            validate(diff.get().unwrap(), &["removed-schema-enum-value"]);
+        let today = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        let issues = waivers.apply(issues, today);
+
+        let waived: Vec<_> = issues
+            .iter()
+            .filter(|issue| issue.is_suppressed())
+            .map(|issue| issue.path.get_path())
+            .collect();
+        assert_eq!(waived, vec!["paths//pets/post/parameters/0/schema/enum"]);
+    }
+}
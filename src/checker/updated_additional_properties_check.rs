@@ -0,0 +1,318 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{
+    schema_ref_value, Severity, ValidationIssue, ValidationIssuer, ValueChange,
+};
+use crate::core::{DiffResult, EitherDiff, MapDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope, PointerDirection};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
+};
+
+use crate::visitor::DiffVisitor;
+
+/// How many unknown properties an object accepts, from closed to open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Openness {
+    Closed,
+    Schema,
+    Open,
+}
+
+impl Openness {
+    fn from_bool(value: Option<&bool>) -> Self {
+        match value {
+            Some(false) => Openness::Closed,
+            _ => Openness::Open,
+        }
+    }
+
+    /// Openness of a side which exists, given as the diff of that side
+    fn from_side(
+        side: &EitherDiff<bool, MayBeRefDiff<SchemaDiff>>,
+        target: bool,
+    ) -> Self {
+        match side {
+            EitherDiff::Left(value) => Self::from_bool(if target {
+                value.get_new()
+            } else {
+                value.get_old()
+            }),
+            EitherDiff::ToLeft(value) => Self::from_bool(value.get()),
+            EitherDiff::Right(_) | EitherDiff::ToRight(_) => Openness::Schema,
+        }
+    }
+}
+
+type AdditionalPropertiesDiff =
+    DiffResult<EitherDiff<bool, MayBeRefDiff<SchemaDiff>>>;
+
+/// Openness of both sides, absent `additionalProperties` allows anything
+fn openness(diff: &AdditionalPropertiesDiff) -> (Openness, Openness) {
+    match diff {
+        DiffResult::None => (Openness::Open, Openness::Open),
+        DiffResult::Added(new) => {
+            (Openness::Open, Openness::from_side(new, true))
+        }
+        DiffResult::Removed(old) => {
+            (Openness::from_side(old, false), Openness::Open)
+        }
+        DiffResult::Same(value) | DiffResult::Updated(value, None) => (
+            Openness::from_side(value, false),
+            Openness::from_side(value, true),
+        ),
+        DiffResult::Updated(new, Some(old)) => (
+            Openness::from_side(old, false),
+            Openness::from_side(new, true),
+        ),
+    }
+}
+
+/// `additionalProperties` of one side, `None` when absent
+fn side_value(diff: &AdditionalPropertiesDiff, target: bool) -> Option<Value> {
+    match diff.get_either(target)? {
+        EitherDiff::Left(value) => {
+            value.get_side(target).map(|value| Value::Bool(*value))
+        }
+        EitherDiff::ToLeft(value) => {
+            value.get().map(|value| Value::Bool(*value))
+        }
+        EitherDiff::Right(schema) => schema_ref_value(schema.get()?, target),
+        // Diffed against nothing, so kept as the source side
+        EitherDiff::ToRight(schema) => schema_ref_value(schema.get()?, false),
+    }
+}
+
+pub struct UpdatedAdditionalPropertiesCheck {
+    pointers: RefCell<Vec<(ValueChange, bool)>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedAdditionalPropertiesCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if schema.additional_properties.is_same_or_none() {
+            return true;
+        }
+
+        let (old, new) = openness(&schema.additional_properties);
+
+        // Requests must still be accepted, responses must still be parsed
+        let breaking = match pointer.direction() {
+            Some(PointerDirection::Request) => new < old,
+            Some(PointerDirection::Response) => new > old,
+            None => return true,
+        };
+
+        // Changes inside the schema are flagged, the checkers descending
+        // into it tell whether they break
+        let schema_updated = matches!(
+            schema.additional_properties.get(),
+            Some(EitherDiff::Right(value)) if value.is_updated()
+        );
+
+        if breaking || schema_updated {
+            self.pointers.borrow_mut().push((
+                (
+                    pointer.add(
+                        &schema.additional_properties,
+                        "additionalProperties",
+                        Some(PathPointerScope::SchemaAdditionalProperties),
+                    ),
+                    side_value(&schema.additional_properties, false),
+                    side_value(&schema.additional_properties, true),
+                ),
+                breaking,
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedAdditionalPropertiesCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedAdditionalPropertiesCheck {
+    fn id(&self) -> &'static str {
+        "updated-additional-properties"
+    }
+
+    fn description(&self) -> &'static str {
+        "Allowed additional properties changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|((path, old_value, new_value), breaking)| {
+                let severity = if breaking {
+                    Severity::Error
+                } else {
+                    Severity::Info
+                };
+                ValidationIssue::new(path, self.id(), breaking)
+                    .with_severity(severity)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_additional_properties_check::UpdatedAdditionalPropertiesCheck;
+    use crate::checker::{Severity, ValidationIssuer};
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_additional_properties_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/additional-properties/schema.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/additional-properties/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedAdditionalPropertiesCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        let request = "paths//pets/post/requestBody/content/application/json/schema/properties";
+        let response = "paths//pets/post/responses/200/content/application/json/schema/properties";
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.path.get_path(), issue.severity))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                // `true` to `false` rejects extra request properties
+                (
+                    format!("{request}/labels/additionalProperties"),
+                    Severity::Error
+                ),
+                // absent to schema restricts extra request properties
+                (
+                    format!("{request}/meta/additionalProperties"),
+                    Severity::Error
+                ),
+                // schema of extra request properties updated
+                (
+                    format!("{request}/counts/additionalProperties"),
+                    Severity::Info
+                ),
+                // `false` to absent lets unknown response properties in
+                (
+                    format!("{response}/labels/additionalProperties"),
+                    Severity::Error
+                ),
+                // `false` to schema lets unknown response properties in
+                (
+                    format!("{response}/counts/additionalProperties"),
+                    Severity::Error
+                ),
+            ]
+        );
+
+        let values: Vec<_> = issues
+            .iter()
+            .map(|issue| (issue.old_value.clone(), issue.new_value.clone()))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                (Some(json!(true)), Some(json!(false))),
+                (None, Some(json!({"type": "string"}))),
+                (
+                    Some(json!({"type": "string"})),
+                    Some(json!({"type": "integer"}))
+                ),
+                (Some(json!(false)), None),
+                (Some(json!(false)), Some(json!({"type": "string"}))),
+            ]
+        );
+    }
+}
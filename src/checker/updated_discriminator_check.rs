@@ -0,0 +1,234 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+pub struct UpdatedDiscriminatorCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedDiscriminatorCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        // Added or dropped discriminators change the whole composition
+        if !schema.discriminator.is_updated() {
+            return true;
+        }
+
+        let Some(discriminator) = schema.discriminator.get() else {
+            return true;
+        };
+
+        let value = |value: Option<&String>| {
+            value.map(|value| Value::from(value.as_str()))
+        };
+
+        let discriminator_pointer =
+            pointer.add(&schema.discriminator, "discriminator", None);
+
+        if !discriminator.property_name.is_same_or_none() {
+            self.pointers.borrow_mut().push((
+                discriminator_pointer.add(
+                    &discriminator.property_name,
+                    "propertyName",
+                    None,
+                ),
+                value(discriminator.property_name.get_old()),
+                value(discriminator.property_name.get_new()),
+            ));
+        }
+
+        if let Some(mapping) = discriminator.mapping.get() {
+            let mapping_pointer = discriminator_pointer.add(
+                &discriminator.mapping,
+                "mapping",
+                None,
+            );
+
+            // Removed or retargeted mapping entries
+            for (name, target) in mapping.iter() {
+                if target.is_removed() || target.is_updated() {
+                    self.pointers.borrow_mut().push((
+                        mapping_pointer.add(target, name, None),
+                        value(target.get_old()),
+                        value(target.get_new()),
+                    ));
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedDiscriminatorCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedDiscriminatorCheck {
+    fn id(&self) -> &'static str {
+        "updated-discriminator"
+    }
+
+    fn description(&self) -> &'static str {
+        "Discriminator property or mapping changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_discriminator_check::UpdatedDiscriminatorCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_discriminator_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/composition/schema-with-compositions.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!(
+                "../../data/checks/composition/schema-with-compositions-altered.json"
+            ),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedDiscriminatorCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 2);
+        // `cat` retargeted
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/responses/200/content/application/json/schema/discriminator/mapping/cat",
+        );
+        // `dog` removed
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "paths//pets/post/responses/200/content/application/json/schema/discriminator/mapping/dog",
+        );
+        assert_eq!(
+            issues[0].old_value,
+            Some(json!("#/components/schemas/Cat"))
+        );
+        assert_eq!(
+            issues[0].new_value,
+            Some(json!("#/components/schemas/Kitten"))
+        );
+        assert_eq!(
+            issues[1].old_value,
+            Some(json!("#/components/schemas/Dog"))
+        );
+        assert_eq!(issues[1].new_value, None);
+    }
+}
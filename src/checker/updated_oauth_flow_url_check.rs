@@ -0,0 +1,159 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::DiffResult;
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::SecuritySchemeDiff;
+use crate::visitor::DiffVisitor;
+
+pub struct UpdatedOAuthFlowUrlCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedOAuthFlowUrlCheck {
+    fn visit_security_scheme(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        security_scheme_diff_result: &'s DiffResult<SecuritySchemeDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(security_scheme) = security_scheme_diff_result.get() else {
+            return false;
+        };
+        let Some(flows) = security_scheme.flows.get() else {
+            return false;
+        };
+
+        let flows_pointer = pointer.add(&security_scheme.flows, "flows", None);
+
+        let flows = [
+            ("implicit", &flows.implicit),
+            ("password", &flows.password),
+            ("clientCredentials", &flows.client_credentials),
+            ("authorizationCode", &flows.authorization_code),
+        ];
+
+        for (name, flow_diff_result) in flows {
+            if !flow_diff_result.is_updated() {
+                continue;
+            }
+            let Some(flow) = flow_diff_result.get() else {
+                continue;
+            };
+
+            let flow_pointer = flows_pointer.add(flow_diff_result, name, None);
+
+            let urls = [
+                ("authorizationUrl", &flow.authorization_url),
+                ("tokenUrl", &flow.token_url),
+                ("refreshUrl", &flow.refresh_url),
+            ];
+
+            for (field, url) in urls {
+                // newly added urls don't affect existing clients
+                if url.is_updated() || url.is_removed() {
+                    let value = |url: Option<&String>| {
+                        url.map(|url| Value::from(url.as_str()))
+                    };
+                    self.pointers.borrow_mut().push((
+                        flow_pointer.add(url, field, None),
+                        value(url.get_old()),
+                        value(url.get_new()),
+                    ));
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl Default for UpdatedOAuthFlowUrlCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedOAuthFlowUrlCheck {
+    fn id(&self) -> &'static str {
+        "updated-oauth-flow-url"
+    }
+
+    fn description(&self) -> &'static str {
+        "OAuth flow URL changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_oauth_flow_url_check::UpdatedOAuthFlowUrlCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_oauth_flow_url_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedOAuthFlowUrlCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "components/securitySchemes/oauth/flows/authorizationCode/tokenUrl",
+        );
+        assert_eq!(
+            issues[0].old_value,
+            Some(json!("https://example.com/oauth/token"))
+        );
+        assert_eq!(
+            issues[0].new_value,
+            Some(json!("https://example.com/oauth/v2/token"))
+        );
+    }
+}
@@ -0,0 +1,348 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{
+    types_value, ValidationIssue, ValidationIssuer, ValueChange,
+};
+use crate::core::{DiffResult, EitherDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PointerDirection};
+use crate::schema_diff::{
+    MayBeRefDiff, OperationDiff, ParameterDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TypeChange {
+    /// Every old value is still accepted, e.g. `int32` to `int64`
+    Widening,
+    /// Some old values are no longer accepted, e.g. `int64` to `int32`
+    Narrowing,
+    Incompatible,
+}
+
+impl TypeChange {
+    fn is_breaking(self, direction: Option<PointerDirection>) -> bool {
+        match (self, direction) {
+            (TypeChange::Widening, Some(PointerDirection::Response)) => true,
+            (TypeChange::Widening, _) => false,
+            (TypeChange::Narrowing, Some(PointerDirection::Response)) => false,
+            (TypeChange::Narrowing, _) => true,
+            (TypeChange::Incompatible, _) => true,
+        }
+    }
+}
+
+fn format_rank(format: &str) -> Option<usize> {
+    match format {
+        "int32" | "float" => Some(1),
+        "int64" | "double" => Some(2),
+        _ => None,
+    }
+}
+
+fn type_change(
+    (old_type, old_format): (Option<&str>, Option<&str>),
+    (new_type, new_format): (Option<&str>, Option<&str>),
+) -> Option<TypeChange> {
+    if old_type != new_type {
+        return Some(match (old_type, new_type) {
+            (Some("integer"), Some("number")) => TypeChange::Widening,
+            (Some("number"), Some("integer")) => TypeChange::Narrowing,
+            _ => TypeChange::Incompatible,
+        });
+    }
+
+    match (old_format, new_format) {
+        (old, new) if old == new => None,
+        (Some(_), None) => Some(TypeChange::Widening),
+        (None, Some(_)) => Some(TypeChange::Narrowing),
+        (Some(old), Some(new)) => match (format_rank(old), format_rank(new)) {
+            (Some(old), Some(new)) if old < new => Some(TypeChange::Widening),
+            (Some(old), Some(new)) if old > new => Some(TypeChange::Narrowing),
+            _ => Some(TypeChange::Incompatible),
+        },
+        (None, None) => None,
+    }
+}
+
+/// Single schema type of one side, `Err` when the type is a list
+fn single_type(
+    r#type: &DiffResult<EitherDiff<String, VecDiff<String, impl Sized>>>,
+    target: bool,
+) -> Result<Option<&str>, ()> {
+    match r#type.get_either(target) {
+        None => Ok(None),
+        Some(EitherDiff::Left(value)) => Ok(if target {
+            value.get_new()
+        } else {
+            value.get_old()
+        }
+        .map(String::as_str)),
+        Some(EitherDiff::ToLeft(value)) => Ok(value.get().map(String::as_str)),
+        _ => Err(()),
+    }
+}
+
+/// Effective `style` and `explode` of parameter for one side
+fn serialization(
+    parameter: &ParameterDiff,
+    target: bool,
+) -> (Option<&str>, Option<bool>) {
+    let (style, explode) = if target {
+        (parameter.style.get_new(), parameter.explode.get_new())
+    } else {
+        (parameter.style.get_old(), parameter.explode.get_old())
+    };
+
+    let style = style.map(String::as_str).or(match parameter.r#in.as_str() {
+        "query" | "cookie" => Some("form"),
+        "path" | "header" => Some("simple"),
+        _ => None,
+    });
+
+    (style, explode.copied().or(Some(style == Some("form"))))
+}
+
+pub struct UpdatedParameterTypeCheck {
+    pointers: RefCell<Vec<(ValueChange, bool)>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedParameterTypeCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        parameter_diff_result: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(parameter) = parameter_diff_result.get() else {
+            return false;
+        };
+
+        let (old_style, old_explode) = serialization(parameter, false);
+        let (new_style, new_explode) = serialization(parameter, true);
+
+        // Both sides have to agree on serialization, so any change breaks
+        if old_style != new_style {
+            self.pointers.borrow_mut().push((
+                (
+                    pointer.add(&parameter.style, "style", None),
+                    old_style.map(Value::from),
+                    new_style.map(Value::from),
+                ),
+                true,
+            ));
+        } else if old_explode != new_explode {
+            self.pointers.borrow_mut().push((
+                (
+                    pointer.add(&parameter.explode, "explode", None),
+                    old_explode.map(Value::from),
+                    new_explode.map(Value::from),
+                ),
+                true,
+            ));
+        }
+
+        true
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if schema.r#type.is_same_or_none() && schema.format.is_same_or_none() {
+            return true;
+        }
+
+        let old_type = single_type(&schema.r#type, false);
+        let new_type = single_type(&schema.r#type, true);
+
+        let change = match (old_type, new_type) {
+            (Ok(old_type), Ok(new_type)) => type_change(
+                (old_type, schema.format.get_old().map(String::as_str)),
+                (new_type, schema.format.get_new().map(String::as_str)),
+            ),
+            _ => Some(TypeChange::Incompatible),
+        };
+
+        if let Some(change) = change {
+            let value_change = if schema.r#type.is_same_or_none() {
+                let format = |format: Option<&String>| {
+                    format.map(|format| Value::from(format.as_str()))
+                };
+                (
+                    pointer.add(&schema.format, "format", None),
+                    format(schema.format.get_old()),
+                    format(schema.format.get_new()),
+                )
+            } else {
+                (
+                    pointer.add(&schema.r#type, "type", None),
+                    types_value(&schema.r#type, false),
+                    types_value(&schema.r#type, true),
+                )
+            };
+            let breaking = change.is_breaking(value_change.0.direction());
+            self.pointers.borrow_mut().push((value_change, breaking));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedParameterTypeCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedParameterTypeCheck {
+    fn id(&self) -> &'static str {
+        "updated-parameter-type"
+    }
+
+    fn description(&self) -> &'static str {
+        "Parameter type or serialization changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|((path, old_value, new_value), breaking)| {
+                ValidationIssue::new(path, self.id(), breaking)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_parameter_type_check::UpdatedParameterTypeCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_parameter_type_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-parameter-type/schema.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-parameter-type/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedParameterTypeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.path.get_path(),
+                    issue.breaking,
+                    issue.old_value.clone(),
+                    issue.new_value.clone(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                // int32 to int64 widens accepted values
+                (
+                    "paths//items/{id}/get/parameters/0/schema/format".into(),
+                    false,
+                    Some(json!("int32")),
+                    Some(json!("int64")),
+                ),
+                // int64 to int32 narrows accepted values
+                (
+                    "paths//items/{id}/get/parameters/1/schema/format".into(),
+                    true,
+                    Some(json!("int64")),
+                    Some(json!("int32")),
+                ),
+                (
+                    "paths//items/{id}/get/parameters/2/explode".into(),
+                    true,
+                    Some(json!(true)),
+                    Some(json!(false)),
+                ),
+                (
+                    "paths//items/{id}/get/parameters/3/schema/type".into(),
+                    true,
+                    Some(json!("string")),
+                    Some(json!("integer")),
+                ),
+                // integer to number widens accepted values
+                (
+                    "paths//items/{id}/get/parameters/5/schema/type".into(),
+                    false,
+                    Some(json!("integer")),
+                    Some(json!("number")),
+                ),
+                // number to integer narrows accepted values
+                (
+                    "paths//items/{id}/get/parameters/6/schema/type".into(),
+                    true,
+                    Some(json!("number")),
+                    Some(json!("integer")),
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
+};
+
+use crate::visitor::DiffVisitor;
+
+/// Names listed in `required` on one side of the diff
+fn required_names(
+    required: &DiffResult<VecDiff<String>>,
+    target: bool,
+) -> Vec<&str> {
+    let exists = if target {
+        !required.is_removed()
+    } else {
+        !required.is_added()
+    };
+
+    match required.get() {
+        Some(names) if exists => names
+            .iter()
+            .filter(|name| {
+                if target {
+                    !name.is_removed()
+                } else {
+                    !name.is_added()
+                }
+            })
+            .filter_map(|name| name.get().map(String::as_str))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub struct UpdatedRequiredBodyPropertyCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedRequiredBodyPropertyCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        // Webhook responses are sent by the consumer
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if schema.required.is_same_or_none() {
+            return true;
+        }
+
+        let Some(properties) = schema.properties.get() else {
+            return true;
+        };
+
+        let old_required = required_names(&schema.required, false);
+
+        for name in required_names(&schema.required, true) {
+            if old_required.contains(&name) {
+                continue;
+            }
+
+            // New properties are reported by `added-required-body-property`
+            let Some(property) = properties.get(name) else {
+                continue;
+            };
+            if !property.is_same() && !property.is_updated() {
+                continue;
+            }
+
+            self.pointers.borrow_mut().push((
+                pointer
+                    .add(
+                        &schema.properties,
+                        "properties",
+                        Some(PathPointerScope::SchemaProperties),
+                    )
+                    .add(
+                        property,
+                        name,
+                        Some(PathPointerScope::SchemaProperty),
+                    ),
+                Some(Value::Bool(false)),
+                Some(Value::Bool(true)),
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedRequiredBodyPropertyCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedRequiredBodyPropertyCheck {
+    fn id(&self) -> &'static str {
+        "updated-required-body-property"
+    }
+
+    fn description(&self) -> &'static str {
+        "Existing request body property made required"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_required_body_property_check::UpdatedRequiredBodyPropertyCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_required_body_property_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-required-body-property/schema.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(include_str!(
+            "../../data/checks/updated-required-body-property/schema-altered.json"
+        ))
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedRequiredBodyPropertyCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 2);
+        // optional `name` made required in existing `required` list
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/name",
+        );
+        // `required` list added to nested schema
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/owner/properties/email",
+        );
+        for issue in &issues {
+            assert_eq!(issue.old_value, Some(json!(false)));
+            assert_eq!(issue.new_value, Some(json!(true)));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff};
+use crate::path_pointer::{PathPointer, PointerDirection};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
+};
+
+use crate::visitor::DiffVisitor;
+
+/// Whether the flag is set on one side of the diff
+fn is_set(flag: &DiffResult<bool>, target: bool) -> bool {
+    let value = if target {
+        flag.get_new()
+    } else {
+        flag.get_old()
+    };
+    value.copied().unwrap_or(false)
+}
+
+pub struct UpdatedSchemaAccessCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSchemaAccessCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        // Consumers can no longer send the property, or no longer receive it
+        let (flag, name) = match pointer.direction() {
+            Some(PointerDirection::Request) => (&schema.read_only, "readOnly"),
+            Some(PointerDirection::Response) => {
+                (&schema.write_only, "writeOnly")
+            }
+            None => return true,
+        };
+
+        if !is_set(flag, false) && is_set(flag, true) {
+            self.pointers.borrow_mut().push((
+                pointer.add(flag, name, None),
+                flag.get_old().copied().map(Value::Bool),
+                flag.get_new().copied().map(Value::Bool),
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedSchemaAccessCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSchemaAccessCheck {
+    fn id(&self) -> &'static str {
+        "updated-schema-access"
+    }
+
+    fn description(&self) -> &'static str {
+        "Property became `readOnly` in request or `writeOnly` in response"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_schema_access_check::UpdatedSchemaAccessCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_schema_access_check() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/nullable-access/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/nullable-access/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedSchemaAccessCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 2);
+        // property became read only in request
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/id/readOnly",
+        );
+        // property became write only in response
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "paths//pets/post/responses/201/content/application/json/schema/properties/secret/writeOnly",
+        );
+        assert_eq!(issues[0].old_value, None);
+        assert_eq!(issues[0].new_value, Some(json!(true)));
+        assert_eq!(issues[1].old_value, None);
+        assert_eq!(issues[1].new_value, Some(json!(true)));
+    }
+}
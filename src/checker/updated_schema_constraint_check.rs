@@ -0,0 +1,700 @@
+use std::cell::RefCell;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::core::{DiffResult, EitherDiff, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PointerDirection};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConstraintChange {
+    /// Fewer values are accepted
+    Tightened,
+    /// More values are accepted
+    Loosened,
+    /// Some values are accepted no more while others are accepted now
+    Replaced,
+}
+
+impl ConstraintChange {
+    fn is_breaking(self, direction: PointerDirection) -> bool {
+        matches!(
+            (self, direction),
+            (ConstraintChange::Replaced, _)
+                | (ConstraintChange::Tightened, PointerDirection::Request)
+                | (ConstraintChange::Loosened, PointerDirection::Response)
+        )
+    }
+}
+
+fn upper_bound<T: PartialOrd>(
+    old: Option<T>,
+    new: Option<T>,
+) -> Option<ConstraintChange> {
+    match (old, new) {
+        (None, Some(_)) => Some(ConstraintChange::Tightened),
+        (Some(_), None) => Some(ConstraintChange::Loosened),
+        (Some(old), Some(new)) if new < old => {
+            Some(ConstraintChange::Tightened)
+        }
+        (Some(old), Some(new)) if new > old => {
+            Some(ConstraintChange::Loosened)
+        }
+        _ => None,
+    }
+}
+
+fn lower_bound<T: PartialOrd>(
+    old: Option<T>,
+    new: Option<T>,
+) -> Option<ConstraintChange> {
+    match (old, new) {
+        (None, Some(_)) => Some(ConstraintChange::Tightened),
+        (Some(_), None) => Some(ConstraintChange::Loosened),
+        (Some(old), Some(new)) if new > old => {
+            Some(ConstraintChange::Tightened)
+        }
+        (Some(old), Some(new)) if new < old => {
+            Some(ConstraintChange::Loosened)
+        }
+        _ => None,
+    }
+}
+
+fn flag(old: Option<bool>, new: Option<bool>) -> Option<ConstraintChange> {
+    match (old.unwrap_or(false), new.unwrap_or(false)) {
+        (false, true) => Some(ConstraintChange::Tightened),
+        (true, false) => Some(ConstraintChange::Loosened),
+        _ => None,
+    }
+}
+
+fn pattern(
+    old: Option<&String>,
+    new: Option<&String>,
+) -> Option<ConstraintChange> {
+    match (old, new) {
+        (None, Some(_)) => Some(ConstraintChange::Tightened),
+        (Some(_), None) => Some(ConstraintChange::Loosened),
+        (Some(old), Some(new)) if old != new => {
+            Some(ConstraintChange::Replaced)
+        }
+        _ => None,
+    }
+}
+
+/// Whether `value` is a whole multiple of `of`, allowing for the rounding
+/// error of decimal fractions such as `0.1`
+fn is_multiple(value: f32, of: f32) -> bool {
+    let ratio = f64::from(value) / f64::from(of);
+    ratio.round() >= 1.0 && (ratio - ratio.round()).abs() <= ratio * 1e-5
+}
+
+fn multiple_of(
+    old: Option<f32>,
+    new: Option<f32>,
+) -> Option<ConstraintChange> {
+    match (old, new) {
+        (None, Some(_)) => Some(ConstraintChange::Tightened),
+        (Some(_), None) => Some(ConstraintChange::Loosened),
+        (Some(old), Some(new)) if old != new => {
+            if is_multiple(new, old) {
+                Some(ConstraintChange::Tightened)
+            } else if is_multiple(old, new) {
+                Some(ConstraintChange::Loosened)
+            } else {
+                Some(ConstraintChange::Replaced)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `exclusiveMaximum`/`exclusiveMinimum` of one side, a flag before 3.1
+/// and a number since
+fn exclusive(
+    diff: &DiffResult<EitherDiff<bool, f32>>,
+    target: bool,
+) -> Option<Value> {
+    fn side<T: Copy + Into<Value>>(
+        diff: &DiffResult<T>,
+        target: bool,
+    ) -> Option<Value> {
+        if target {
+            diff.get_new()
+        } else {
+            diff.get_old()
+        }
+        .map(|value| (*value).into())
+    }
+
+    match diff.get_either(target)? {
+        EitherDiff::Left(value) => side(value, target),
+        EitherDiff::Right(value) => side(value, target),
+        EitherDiff::ToLeft(value) => value.get().map(|value| (*value).into()),
+        EitherDiff::ToRight(value) => value.get().map(|value| (*value).into()),
+    }
+}
+
+/// Exclusive bound of one side: the number itself, or the inclusive bound
+/// when flagged as exclusive
+fn exclusive_bound(
+    exclusive: Option<&Value>,
+    bound: &DiffResult<f32>,
+    target: bool,
+) -> Option<f64> {
+    match exclusive? {
+        Value::Bool(true) => if target {
+            bound.get_new()
+        } else {
+            bound.get_old()
+        }
+        .map(|bound| f64::from(*bound)),
+        Value::Number(number) => number.as_f64(),
+        _ => None,
+    }
+}
+
+/// Flags are compared as such, leaving their bound to `maximum`/`minimum`,
+/// numbers are compared as bounds
+fn exclusive_change(
+    diff: &DiffResult<EitherDiff<bool, f32>>,
+    bound: &DiffResult<f32>,
+    compare: fn(Option<f64>, Option<f64>) -> Option<ConstraintChange>,
+) -> Option<ConstraintChange> {
+    let old = exclusive(diff, false);
+    let new = exclusive(diff, true);
+
+    let is_number =
+        |value: &Option<Value>| value.as_ref().is_some_and(Value::is_number);
+    if !is_number(&old) && !is_number(&new) {
+        return flag(
+            old.and_then(|value| value.as_bool()),
+            new.and_then(|value| value.as_bool()),
+        );
+    }
+
+    compare(
+        exclusive_bound(old.as_ref(), bound, false),
+        exclusive_bound(new.as_ref(), bound, true),
+    )
+}
+
+fn values<T: Serialize>(
+    diff: &DiffResult<T>,
+) -> (Option<Value>, Option<Value>) {
+    let to_value = |value: &T| serde_json::to_value(value).ok();
+    (
+        diff.get_old().and_then(to_value),
+        diff.get_new().and_then(to_value),
+    )
+}
+
+/// Changed keyword with its values on both sides
+struct ConstraintIssue {
+    pointer: PathPointer,
+    change: ConstraintChange,
+    old_value: Option<Value>,
+    new_value: Option<Value>,
+}
+
+/// Validation keyword of a schema compared by `UpdatedSchemaConstraintCheck`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SchemaConstraint {
+    MaxLength,
+    MinLength,
+    Maximum,
+    Minimum,
+    ExclusiveMaximum,
+    ExclusiveMinimum,
+    Pattern,
+    MaxItems,
+    MinItems,
+    UniqueItems,
+    MultipleOf,
+    MaxProperties,
+    MinProperties,
+}
+
+impl SchemaConstraint {
+    pub const ALL: [SchemaConstraint; 13] = [
+        SchemaConstraint::MaxLength,
+        SchemaConstraint::MinLength,
+        SchemaConstraint::Maximum,
+        SchemaConstraint::Minimum,
+        SchemaConstraint::ExclusiveMaximum,
+        SchemaConstraint::ExclusiveMinimum,
+        SchemaConstraint::Pattern,
+        SchemaConstraint::MaxItems,
+        SchemaConstraint::MinItems,
+        SchemaConstraint::UniqueItems,
+        SchemaConstraint::MultipleOf,
+        SchemaConstraint::MaxProperties,
+        SchemaConstraint::MinProperties,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            SchemaConstraint::MaxLength => "updated-schema-max-length",
+            SchemaConstraint::MinLength => "updated-schema-min-length",
+            SchemaConstraint::Maximum => "updated-schema-maximum",
+            SchemaConstraint::Minimum => "updated-schema-minimum",
+            SchemaConstraint::ExclusiveMaximum => {
+                "updated-schema-exclusive-maximum"
+            }
+            SchemaConstraint::ExclusiveMinimum => {
+                "updated-schema-exclusive-minimum"
+            }
+            SchemaConstraint::Pattern => "updated-schema-pattern",
+            SchemaConstraint::MaxItems => "updated-schema-max-items",
+            SchemaConstraint::MinItems => "updated-schema-min-items",
+            SchemaConstraint::UniqueItems => "updated-schema-unique-items",
+            SchemaConstraint::MultipleOf => "updated-schema-multiple-of",
+            SchemaConstraint::MaxProperties => "updated-schema-max-properties",
+            SchemaConstraint::MinProperties => "updated-schema-min-properties",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            SchemaConstraint::MaxLength => "Maximum string length changed",
+            SchemaConstraint::MinLength => "Minimum string length changed",
+            SchemaConstraint::Maximum => "Maximum value changed",
+            SchemaConstraint::Minimum => "Minimum value changed",
+            SchemaConstraint::ExclusiveMaximum => "Exclusive maximum changed",
+            SchemaConstraint::ExclusiveMinimum => "Exclusive minimum changed",
+            SchemaConstraint::Pattern => "String pattern changed",
+            SchemaConstraint::MaxItems => "Maximum array length changed",
+            SchemaConstraint::MinItems => "Minimum array length changed",
+            SchemaConstraint::UniqueItems => "Array item uniqueness changed",
+            SchemaConstraint::MultipleOf => "Number multiple changed",
+            SchemaConstraint::MaxProperties => {
+                "Maximum property count changed"
+            }
+            SchemaConstraint::MinProperties => {
+                "Minimum property count changed"
+            }
+        }
+    }
+
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            SchemaConstraint::MaxLength => "maxLength",
+            SchemaConstraint::MinLength => "minLength",
+            SchemaConstraint::Maximum => "maximum",
+            SchemaConstraint::Minimum => "minimum",
+            SchemaConstraint::ExclusiveMaximum => "exclusiveMaximum",
+            SchemaConstraint::ExclusiveMinimum => "exclusiveMinimum",
+            SchemaConstraint::Pattern => "pattern",
+            SchemaConstraint::MaxItems => "maxItems",
+            SchemaConstraint::MinItems => "minItems",
+            SchemaConstraint::UniqueItems => "uniqueItems",
+            SchemaConstraint::MultipleOf => "multipleOf",
+            SchemaConstraint::MaxProperties => "maxProperties",
+            SchemaConstraint::MinProperties => "minProperties",
+        }
+    }
+
+    /// Pointer to the keyword and how it changed, if it did
+    fn compare(
+        &self,
+        pointer: &PathPointer,
+        schema: &SchemaDiff,
+    ) -> Option<ConstraintIssue> {
+        // Missing lower length/count bounds are the same as zero
+        fn usize_lower_bound(
+            diff: &DiffResult<usize>,
+        ) -> Option<ConstraintChange> {
+            lower_bound(
+                Some(diff.get_old().copied().unwrap_or_default()),
+                Some(diff.get_new().copied().unwrap_or_default()),
+            )
+        }
+
+        let (change, keyword_pointer, values) = match self {
+            SchemaConstraint::MaxLength => (
+                upper_bound(
+                    schema.max_length.get_old().copied(),
+                    schema.max_length.get_new().copied(),
+                ),
+                pointer.add(&schema.max_length, self.keyword(), None),
+                values(&schema.max_length),
+            ),
+            SchemaConstraint::MinLength => (
+                usize_lower_bound(&schema.min_length),
+                pointer.add(&schema.min_length, self.keyword(), None),
+                values(&schema.min_length),
+            ),
+            SchemaConstraint::Maximum => (
+                upper_bound(
+                    schema.maximum.get_old().copied(),
+                    schema.maximum.get_new().copied(),
+                ),
+                pointer.add(&schema.maximum, self.keyword(), None),
+                values(&schema.maximum),
+            ),
+            SchemaConstraint::Minimum => (
+                lower_bound(
+                    schema.minimum.get_old().copied(),
+                    schema.minimum.get_new().copied(),
+                ),
+                pointer.add(&schema.minimum, self.keyword(), None),
+                values(&schema.minimum),
+            ),
+            SchemaConstraint::ExclusiveMaximum => (
+                exclusive_change(
+                    &schema.exclusive_maximum,
+                    &schema.maximum,
+                    upper_bound,
+                ),
+                pointer.add(&schema.exclusive_maximum, self.keyword(), None),
+                (
+                    exclusive(&schema.exclusive_maximum, false),
+                    exclusive(&schema.exclusive_maximum, true),
+                ),
+            ),
+            SchemaConstraint::ExclusiveMinimum => (
+                exclusive_change(
+                    &schema.exclusive_minimum,
+                    &schema.minimum,
+                    lower_bound,
+                ),
+                pointer.add(&schema.exclusive_minimum, self.keyword(), None),
+                (
+                    exclusive(&schema.exclusive_minimum, false),
+                    exclusive(&schema.exclusive_minimum, true),
+                ),
+            ),
+            SchemaConstraint::Pattern => (
+                pattern(schema.pattern.get_old(), schema.pattern.get_new()),
+                pointer.add(&schema.pattern, self.keyword(), None),
+                values(&schema.pattern),
+            ),
+            SchemaConstraint::MaxItems => (
+                upper_bound(
+                    schema.max_items.get_old().copied(),
+                    schema.max_items.get_new().copied(),
+                ),
+                pointer.add(&schema.max_items, self.keyword(), None),
+                values(&schema.max_items),
+            ),
+            SchemaConstraint::MinItems => (
+                usize_lower_bound(&schema.min_items),
+                pointer.add(&schema.min_items, self.keyword(), None),
+                values(&schema.min_items),
+            ),
+            SchemaConstraint::UniqueItems => (
+                flag(
+                    schema.unique_items.get_old().copied(),
+                    schema.unique_items.get_new().copied(),
+                ),
+                pointer.add(&schema.unique_items, self.keyword(), None),
+                values(&schema.unique_items),
+            ),
+            SchemaConstraint::MultipleOf => (
+                multiple_of(
+                    schema.multiple_of.get_old().copied(),
+                    schema.multiple_of.get_new().copied(),
+                ),
+                pointer.add(&schema.multiple_of, self.keyword(), None),
+                values(&schema.multiple_of),
+            ),
+            SchemaConstraint::MaxProperties => (
+                upper_bound(
+                    schema.max_properties.get_old().copied(),
+                    schema.max_properties.get_new().copied(),
+                ),
+                pointer.add(&schema.max_properties, self.keyword(), None),
+                values(&schema.max_properties),
+            ),
+            SchemaConstraint::MinProperties => (
+                usize_lower_bound(&schema.min_properties),
+                pointer.add(&schema.min_properties, self.keyword(), None),
+                values(&schema.min_properties),
+            ),
+        };
+
+        change.map(|change| ConstraintIssue {
+            pointer: keyword_pointer,
+            change,
+            old_value: values.0,
+            new_value: values.1,
+        })
+    }
+}
+
+/// Flags tightened constraints of request schemas and loosened constraints
+/// of response schemas, one rule per `SchemaConstraint`
+pub struct UpdatedSchemaConstraintCheck {
+    constraint: SchemaConstraint,
+    pointers: RefCell<Vec<ConstraintIssue>>,
+}
+
+impl UpdatedSchemaConstraintCheck {
+    pub fn new(constraint: SchemaConstraint) -> Self {
+        Self {
+            constraint,
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSchemaConstraintCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(direction) = pointer.direction() else {
+            return false;
+        };
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if let Some(issue) = self.constraint.compare(pointer, schema) {
+            if issue.change.is_breaking(direction) {
+                self.pointers.borrow_mut().push(issue);
+            }
+        }
+
+        true
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSchemaConstraintCheck {
+    fn id(&self) -> &'static str {
+        self.constraint.id()
+    }
+
+    fn description(&self) -> &'static str {
+        self.constraint.description()
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|issue| {
+                ValidationIssue::new(issue.pointer, self.id(), true)
+                    .with_values(issue.old_value, issue.new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checker::updated_schema_constraint_check::{
+        SchemaConstraint, UpdatedSchemaConstraintCheck,
+    };
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+    use crate::schemas::openapi310::schema::OpenApi310;
+
+    #[test]
+    fn test_updated_schema_constraint_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-schema-constraint/schema.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/updated-schema-constraint/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let mut found = Vec::new();
+        for constraint in SchemaConstraint::ALL {
+            let checker = UpdatedSchemaConstraintCheck::new(constraint);
+            crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+            found.extend(
+                checker
+                    .issues()
+                    .unwrap()
+                    .into_iter()
+                    .map(|issue| (constraint.id(), issue.path.get_path())),
+            );
+        }
+
+        let body = "paths//pets/post/requestBody/content/application/json/\
+                    schema/properties";
+        let response = "paths//pets/post/responses/200/content/\
+                        application/json/schema/properties";
+
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "updated-schema-max-length",
+                    format!("{body}/name/maxLength")
+                ),
+                (
+                    "updated-schema-max-length",
+                    format!("{response}/name/maxLength")
+                ),
+                (
+                    "updated-schema-maximum",
+                    "paths//pets/post/parameters/0/schema/maximum".to_owned()
+                ),
+                ("updated-schema-minimum", format!("{body}/age/minimum")),
+                ("updated-schema-pattern", format!("{body}/code/pattern")),
+                (
+                    "updated-schema-unique-items",
+                    format!("{response}/tags/uniqueItems")
+                ),
+                (
+                    "updated-schema-multiple-of",
+                    format!("{body}/amount/multipleOf")
+                ),
+                // 0.3 is a multiple of 0.1 despite the float rounding
+                (
+                    "updated-schema-multiple-of",
+                    format!("{body}/step/multipleOf")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_updated_schema_exclusive_bound_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/updated-schema-constraint/schema-310.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi310>(include_str!(
+                "../../data/checks/updated-schema-constraint/\
+                 schema-310-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let mut found = Vec::new();
+        for constraint in [
+            SchemaConstraint::ExclusiveMaximum,
+            SchemaConstraint::ExclusiveMinimum,
+        ] {
+            let checker = UpdatedSchemaConstraintCheck::new(constraint);
+            crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+            found.extend(checker.issues().unwrap().into_iter().map(|issue| {
+                (issue.path.get_path(), issue.old_value, issue.new_value)
+            }));
+        }
+
+        let body = "paths//pets/post/requestBody/content/application/json/\
+                    schema/properties";
+        let response = "paths//pets/post/responses/200/content/\
+                        application/json/schema/properties";
+
+        assert_eq!(
+            found,
+            vec![
+                // `ratio` and `size` accept more requests and fewer responses
+                (
+                    format!("{body}/count/exclusiveMaximum"),
+                    Some(10.0.into()),
+                    Some(5.0.into())
+                ),
+                (
+                    format!("{body}/score/exclusiveMinimum"),
+                    Some(0.0.into()),
+                    Some(1.0.into())
+                ),
+                (
+                    format!("{response}/total/exclusiveMinimum"),
+                    Some(1.0.into()),
+                    Some(0.0.into())
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::cell::RefCell;
+
+use serde_json::{Map, Value};
+
+use crate::checker::config::BoolOption;
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PathPointerScope};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+
+use crate::visitor::DiffVisitor;
+
+pub struct UpdatedSchemaDefaultCheck {
+    breaking: BoolOption,
+    /// Paths of optional properties and parameter schemas seen so far
+    optional: RefCell<Vec<String>>,
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl UpdatedSchemaDefaultCheck {
+    /// Checker reporting issues either as breaking or as warnings
+    pub fn new(breaking: bool) -> Self {
+        Self {
+            breaking: BoolOption::new("breaking", breaking),
+            optional: RefCell::new(vec![]),
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSchemaDefaultCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        // Webhook responses are sent by the consumer
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated() && pointer.is_request()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        parameter_diff_result: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(parameter) = parameter_diff_result.get() else {
+            return false;
+        };
+
+        if parameter.required.get_new() != Some(&true) {
+            self.optional.borrow_mut().push(
+                pointer.add(&parameter.schema, "schema", None).get_path(),
+            );
+        }
+
+        true
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        // Properties are visited after the schema listing them as required
+        if let Some(properties) = schema.properties.get() {
+            let required: Vec<_> = schema
+                .required
+                .get_new()
+                .map(|required| {
+                    required
+                        .iter()
+                        .filter(|name| !name.is_removed())
+                        .filter_map(|name| name.get())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let properties_pointer = pointer.add(
+                &schema.properties,
+                "properties",
+                Some(PathPointerScope::SchemaProperties),
+            );
+            for (name, property) in properties.iter() {
+                if !required.contains(&name) {
+                    self.optional.borrow_mut().push(
+                        properties_pointer
+                            .add(property, name, None)
+                            .get_path(),
+                    );
+                }
+            }
+        }
+
+        if !schema.default.is_updated()
+            && !schema.default.is_added()
+            && !schema.default.is_removed()
+        {
+            return true;
+        }
+
+        let path = pointer.get_path();
+        if self.optional.borrow().contains(&path) {
+            self.pointers.borrow_mut().push((
+                pointer.add(&schema.default, "default", None),
+                schema.default.get_old().cloned(),
+                schema.default.get_new().cloned(),
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedSchemaDefaultCheck {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSchemaDefaultCheck {
+    fn id(&self) -> &'static str {
+        "updated-schema-default"
+    }
+
+    fn description(&self) -> &'static str {
+        "Default value of optional field changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn configure(&mut self, options: &Map<String, Value>) {
+        self.breaking.configure(options);
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        self.optional.borrow_mut().clear();
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), self.breaking.get())
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_schema_default_check::UpdatedSchemaDefaultCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_schema_default_check() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/default-format/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/default-format/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedSchemaDefaultCheck::new(false);
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 3);
+        assert!(issues.iter().all(|issue| !issue.breaking));
+
+        // default of optional property updated
+        let issue = issues.first().unwrap();
+        assert_eq!(
+            issue.path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/status/default",
+        );
+        assert_eq!(issue.old_value, Some(json!("available")));
+        assert_eq!(issue.new_value, Some(json!("pending")));
+
+        // default added to optional property
+        let issue = issues.get(1).unwrap();
+        assert_eq!(
+            issue.path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/tag/default",
+        );
+        assert_eq!(issue.old_value, None);
+        assert_eq!(issue.new_value, Some(json!("none")));
+
+        // default of optional query parameter updated
+        let issue = issues.get(2).unwrap();
+        assert_eq!(
+            issue.path.get_path(),
+            "paths//pets/post/parameters/0/schema/default",
+        );
+        assert_eq!(issue.old_value, Some(json!(10)));
+        assert_eq!(issue.new_value, Some(json!(20)));
+    }
+}
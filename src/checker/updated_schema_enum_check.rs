@@ -0,0 +1,244 @@
+use std::cell::RefCell;
+
+use serde_json::{Map, Value};
+
+use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PointerDirection};
+
+use crate::checker::config::BoolOption;
+use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+use crate::visitor::DiffVisitor;
+
+/// Extension marking an enum as open to new values, so consumers are
+/// expected to handle values they do not know
+pub const EXTENSIBLE_ENUM_EXTENSION: &str = "x-schemadoc-extensible-enum";
+
+/// Whether the target side of a schema sets the extension to `true`
+fn is_extensible(schema: &SchemaDiff) -> bool {
+    schema
+        .custom_fields
+        .get_new()
+        .and_then(|fields| fields.get(EXTENSIBLE_ENUM_EXTENSION))
+        .and_then(|value| value.get_new())
+        == Some(&Value::Bool(true))
+}
+
+/// Pointer to the enum with its old and new values
+type EnumChange = (PathPointer, Value, Value);
+
+pub struct UpdatedSchemaEnumCheck {
+    /// Values added to responses break, on by default
+    response_additions_breaking: BoolOption,
+    pointers: RefCell<Vec<EnumChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSchemaEnumCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if !schema.r#enum.is_updated() {
+            return true;
+        }
+
+        let Some(values) = schema.r#enum.get() else {
+            return true;
+        };
+
+        // Consumers may not handle new values they receive. Values
+        // removed from requests are reported by `removed-schema-enum-value`,
+        // which skips responses
+        let is_response =
+            pointer.direction() == Some(PointerDirection::Response);
+        if !is_response || is_extensible(schema) {
+            return true;
+        }
+
+        if values.iter().any(|value| value.is_added()) {
+            let side = |target| {
+                Value::Array(
+                    values.side(target).into_iter().cloned().collect(),
+                )
+            };
+            self.pointers.borrow_mut().push((
+                pointer.add(&schema.r#enum, "enum", None),
+                side(false),
+                side(true),
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedSchemaEnumCheck {
+    fn default() -> Self {
+        Self {
+            response_additions_breaking: BoolOption::new(
+                "responseAdditionsBreaking",
+                true,
+            ),
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSchemaEnumCheck {
+    fn id(&self) -> &'static str {
+        "updated-schema-enum"
+    }
+
+    fn description(&self) -> &'static str {
+        "Value added to response enum"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn configure(&mut self, options: &Map<String, Value>) {
+        self.response_additions_breaking.configure(options);
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old, new)| {
+                ValidationIssue::new(
+                    path,
+                    self.id(),
+                    self.response_additions_breaking.get(),
+                )
+                .with_values(Some(old), Some(new))
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_schema_enum_check::UpdatedSchemaEnumCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_schema_enum_check() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/schema-enum/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/schema-enum/schema-altered.json"),
+        )
+        .unwrap()
+        .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedSchemaEnumCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.path.get_path(),
+                    issue.old_value.clone(),
+                    issue.new_value.clone(),
+                )
+            })
+            .collect();
+
+        // Values removed from the request body and the query parameter
+        // are left to `removed-schema-enum-value`, `color` is extensible
+        assert_eq!(
+            found,
+            vec![(
+                "paths//pets/post/responses/200/content/application/json/schema/properties/status/enum".into(),
+                Some(json!(["active"])),
+                Some(json!(["active", "archived"])),
+            )]
+        );
+    }
+}
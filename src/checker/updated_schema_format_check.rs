@@ -0,0 +1,189 @@
+use std::cell::RefCell;
+
+use serde_json::{Map, Value};
+
+use crate::checker::config::BoolOption;
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::{DiffResult, MapDiff};
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
+};
+
+use crate::visitor::DiffVisitor;
+
+/// Body schema formats, parameters are covered by `updated-parameter-type`
+pub struct UpdatedSchemaFormatCheck {
+    breaking: BoolOption,
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl UpdatedSchemaFormatCheck {
+    /// Checker reporting issues either as breaking or as warnings
+    pub fn new(breaking: bool) -> Self {
+        Self {
+            breaking: BoolOption::new("breaking", breaking),
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSchemaFormatCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if !schema.format.is_same_or_none() {
+            self.pointers.borrow_mut().push((
+                pointer.add(&schema.format, "format", None),
+                schema.format.get_old().cloned().map(Value::String),
+                schema.format.get_new().cloned().map(Value::String),
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedSchemaFormatCheck {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSchemaFormatCheck {
+    fn id(&self) -> &'static str {
+        "updated-schema-format"
+    }
+
+    fn description(&self) -> &'static str {
+        "Schema format changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn configure(&mut self, options: &Map<String, Value>) {
+        self.breaking.configure(options);
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), self.breaking.get())
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_schema_format_check::UpdatedSchemaFormatCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_schema_format_check() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/default-format/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/default-format/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedSchemaFormatCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|issue| issue.breaking));
+
+        // `date-time` to `date`
+        let issue = issues.first().unwrap();
+        assert_eq!(
+            issue.path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/createdAt/format",
+        );
+        assert_eq!(issue.old_value, Some(json!("date-time")));
+        assert_eq!(issue.new_value, Some(json!("date")));
+
+        // `uuid` to plain string
+        let issue = issues.get(1).unwrap();
+        assert_eq!(
+            issue.path.get_path(),
+            "paths//pets/post/responses/200/content/application/json/schema/properties/id/format",
+        );
+        assert_eq!(issue.old_value, Some(json!("uuid")));
+        assert_eq!(issue.new_value, None);
+    }
+}
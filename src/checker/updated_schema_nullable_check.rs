@@ -0,0 +1,220 @@
+use std::cell::RefCell;
+
+use crate::checker::updated_schema_type_check::schema_types;
+use crate::checker::{
+    types_value, ValidationIssue, ValidationIssuer, ValueChange,
+};
+use crate::core::{DiffResult, EitherDiff, MapDiff, VecDiff};
+use crate::path_pointer::{PathPointer, PointerDirection};
+use crate::schema_diff::{
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
+    RequestBodyDiff, ResponseDiff, SchemaDiff,
+};
+
+use crate::visitor::DiffVisitor;
+
+/// Whether `null` is allowed by one side of the type diff, `None` when the
+/// type is not set on that side
+fn is_nullable(
+    r#type: &DiffResult<EitherDiff<String, VecDiff<String, impl Sized>>>,
+    target: bool,
+) -> Option<bool> {
+    schema_types(r#type, target).map(|types| types.contains(&"null"))
+}
+
+pub struct UpdatedSchemaNullableCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSchemaNullableCheck {
+    fn visit_operation(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        _: &'s DiffResult<OperationDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_request_body(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<RequestBodyDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MayBeRefDiff<ResponseDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_types(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_media_type(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MediaTypeDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameters(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_parameter(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<ParameterDiff>,
+    ) -> bool {
+        pointer.is_updated()
+    }
+
+    fn visit_schema(
+        &self,
+        pointer: &PathPointer,
+        schema_diff_result: &'s DiffResult<SchemaDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        let Some(schema) = schema_diff_result.get() else {
+            return false;
+        };
+
+        if !schema.r#type.is_updated() {
+            return true;
+        }
+
+        let (Some(old), Some(new)) = (
+            is_nullable(&schema.r#type, false),
+            is_nullable(&schema.r#type, true),
+        ) else {
+            return true;
+        };
+
+        // Consumers sending null are rejected, or receive unexpected null
+        let breaking = match pointer.direction() {
+            Some(PointerDirection::Request) => old && !new,
+            Some(PointerDirection::Response) => !old && new,
+            None => false,
+        };
+
+        if breaking {
+            self.pointers.borrow_mut().push((
+                pointer.add(&schema.r#type, "type", None),
+                types_value(&schema.r#type, false),
+                types_value(&schema.r#type, true),
+            ));
+        }
+
+        true
+    }
+}
+
+impl Default for UpdatedSchemaNullableCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSchemaNullableCheck {
+    fn id(&self) -> &'static str {
+        "updated-schema-nullable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Null dropped from request or added to response"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_schema_nullable_check::UpdatedSchemaNullableCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_schema_nullable_check() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/nullable-access/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/nullable-access/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedSchemaNullableCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 3);
+        // `nullable` dropped from request property
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "paths//pets/post/requestBody/content/application/json/schema/properties/nickname/type",
+        );
+        // `nullable` added to response property
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "paths//pets/post/responses/201/content/application/json/schema/properties/name/type",
+        );
+        // `nullable` dropped from query parameter schema
+        assert_eq!(
+            issues.get(2).unwrap().path.get_path(),
+            "paths//pets/post/parameters/0/schema/type",
+        );
+        assert_eq!(issues[0].old_value, Some(json!(["string", "null"])));
+        assert_eq!(issues[0].new_value, Some(json!("string")));
+        assert_eq!(issues[1].old_value, Some(json!("string")));
+        assert_eq!(issues[1].new_value, Some(json!(["string", "null"])));
+        assert_eq!(issues[2].old_value, Some(json!(["boolean", "null"])));
+        assert_eq!(issues[2].new_value, Some(json!("boolean")));
+    }
+}
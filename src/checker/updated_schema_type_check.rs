@@ -1,17 +1,78 @@
 use std::cell::RefCell;
 
-use crate::core::{DiffResult, MapDiff, VecDiff};
+use crate::core::{DiffResult, EitherDiff, MapDiff, VecDiff};
 use crate::path_pointer::PathPointer;
 
-use crate::checker::{ValidationIssue, ValidationIssuer};
+use crate::checker::{
+    types_value, ValidationIssue, ValidationIssuer, ValueChange,
+};
 use crate::schema_diff::{
-    MayBeRefDiff, MediaTypeDiff, OperationDiff, ParameterDiff,
-    RequestBodyDiff, ResponseDiff, SchemaDiff,
+    MayBeRefDiff, MediaTypeDiff, OperationDiff, RequestBodyDiff, ResponseDiff,
+    SchemaDiff,
 };
 use crate::visitor::DiffVisitor;
 
+/// Types of one side of the type diff, `None` when the type is not set on
+/// that side
+pub(crate) fn schema_types(
+    r#type: &DiffResult<EitherDiff<String, VecDiff<String, impl Sized>>>,
+    target: bool,
+) -> Option<Vec<&str>> {
+    let side = r#type.get_either(target)?;
+
+    // Values switched to are diffed against nothing, so take them as is
+    let types = match side {
+        EitherDiff::Left(value) => {
+            let value = if target {
+                value.get_new()
+            } else {
+                value.get_old()
+            };
+            vec![value?.as_str()]
+        }
+        EitherDiff::ToLeft(value) => vec![value.get()?.as_str()],
+        EitherDiff::Right(values) => values
+            .get()?
+            .iter()
+            .filter(|value| {
+                if target {
+                    !value.is_removed()
+                } else {
+                    !value.is_added()
+                }
+            })
+            .filter_map(|value| value.get().map(String::as_str))
+            .collect(),
+        EitherDiff::ToRight(values) => values
+            .get()?
+            .iter()
+            .filter_map(|value| value.get().map(String::as_str))
+            .collect(),
+    };
+
+    Some(types)
+}
+
+/// Whether the types differ by more than `null`, which is reported by
+/// `updated-schema-nullable`
+fn is_type_changed(
+    r#type: &DiffResult<EitherDiff<String, VecDiff<String, impl Sized>>>,
+) -> bool {
+    let non_null = |target| {
+        schema_types(r#type, target).map(|types| {
+            let mut types: Vec<_> =
+                types.into_iter().filter(|value| *value != "null").collect();
+            types.sort_unstable();
+            types.dedup();
+            types
+        })
+    };
+
+    non_null(false) != non_null(true)
+}
+
 pub struct UpdatedSchemaTypeCheck {
-    pointers: RefCell<Vec<PathPointer>>,
+    pointers: RefCell<Vec<ValueChange>>,
 }
 
 impl<'s> DiffVisitor<'s> for UpdatedSchemaTypeCheck {
@@ -56,22 +117,6 @@ impl<'s> DiffVisitor<'s> for UpdatedSchemaTypeCheck {
         pointer.is_updated()
     }
 
-    fn visit_parameters(
-        &self,
-        pointer: &PathPointer,
-        _: &'s DiffResult<VecDiff<MayBeRefDiff<ParameterDiff>>>,
-    ) -> bool {
-        pointer.is_updated()
-    }
-
-    fn visit_parameter(
-        &self,
-        pointer: &PathPointer,
-        _: &'s DiffResult<ParameterDiff>,
-    ) -> bool {
-        pointer.is_updated()
-    }
-
     fn visit_schema(
         &self,
         pointer: &PathPointer,
@@ -85,8 +130,12 @@ impl<'s> DiffVisitor<'s> for UpdatedSchemaTypeCheck {
             return false;
         };
 
-        if schema.r#type.is_updated() {
-            self.pointers.borrow_mut().push(pointer.clone())
+        if schema.r#type.is_updated() && is_type_changed(&schema.r#type) {
+            self.pointers.borrow_mut().push((
+                pointer.clone(),
+                types_value(&schema.r#type, false),
+                types_value(&schema.r#type, true),
+            ))
         }
 
         true
@@ -106,6 +155,10 @@ impl<'s> ValidationIssuer<'s> for UpdatedSchemaTypeCheck {
         "updated-schema-type"
     }
 
+    fn description(&self) -> &'static str {
+        "Schema type changed"
+    }
+
     fn visitor(&self) -> &dyn DiffVisitor<'s> {
         self
     }
@@ -115,7 +168,10 @@ impl<'s> ValidationIssuer<'s> for UpdatedSchemaTypeCheck {
 
         let issues = pointers
             .into_iter()
-            .map(|path| ValidationIssue::new(path, self.id(), true))
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
             .collect::<Vec<ValidationIssue>>();
 
         Some(issues)
@@ -124,6 +180,8 @@ impl<'s> ValidationIssuer<'s> for UpdatedSchemaTypeCheck {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::checker::updated_schema_type_check::UpdatedSchemaTypeCheck;
     use crate::checker::ValidationIssuer;
     use crate::get_schema_diff;
@@ -153,12 +211,39 @@ mod tests {
 
         assert_eq!(issues.len(), 2);
         assert_eq!(
-            issues.get(0).unwrap().path.get_path(),
+            issues.first().unwrap().path.get_path(),
             "paths//test/post/requestBody/content/application/json/schema",
         );
         assert_eq!(
             issues.get(1).unwrap().path.get_path(),
             "paths//test2/post/responses/404/content/application/json/schema/properties/prop2",
         );
+        assert_eq!(issues[0].old_value, Some(json!("string")));
+        assert_eq!(issues[0].new_value, Some(json!("number")));
+        assert_eq!(issues[1].old_value, Some(json!("string")));
+        assert_eq!(issues[1].new_value, Some(json!("object")));
+    }
+
+    #[test]
+    fn test_updated_schema_type_check_ignores_null() {
+        let src_schema: HttpSchema = serde_json::from_str::<OpenApi303>(
+            include_str!("../../data/checks/nullable-access/schema.json"),
+        )
+        .unwrap()
+        .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/nullable-access/schema-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        // Only `nullable` changes, reported by `updated-schema-nullable`
+        let checker = UpdatedSchemaTypeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        assert!(checker.issues().unwrap().is_empty());
     }
 }
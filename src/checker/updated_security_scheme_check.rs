@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+
+use serde_json::Value;
+
+use crate::checker::{ValidationIssue, ValidationIssuer, ValueChange};
+use crate::core::DiffResult;
+use crate::path_pointer::PathPointer;
+use crate::schema_diff::SecuritySchemeDiff;
+use crate::visitor::DiffVisitor;
+
+pub struct UpdatedSecuritySchemeCheck {
+    pointers: RefCell<Vec<ValueChange>>,
+}
+
+impl<'s> DiffVisitor<'s> for UpdatedSecuritySchemeCheck {
+    fn visit_security_scheme(
+        &self,
+        pointer: &PathPointer,
+        _: &str,
+        security_scheme_diff_result: &'s DiffResult<SecuritySchemeDiff>,
+    ) -> bool {
+        if !pointer.is_updated() {
+            return false;
+        }
+
+        if let Some(security_scheme) = security_scheme_diff_result.get() {
+            let fields = [
+                ("type", &security_scheme.r#type),
+                ("in", &security_scheme.r#in),
+                ("name", &security_scheme.name),
+            ];
+
+            for (field, diff_result) in fields {
+                if !diff_result.is_same_or_none() {
+                    let value = |value: Option<&String>| {
+                        value.map(|value| Value::from(value.as_str()))
+                    };
+                    self.pointers.borrow_mut().push((
+                        pointer.add_component(diff_result, Some(field), None),
+                        value(diff_result.get_old()),
+                        value(diff_result.get_new()),
+                    ));
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl Default for UpdatedSecuritySchemeCheck {
+    fn default() -> Self {
+        Self {
+            pointers: RefCell::new(vec![]),
+        }
+    }
+}
+
+impl<'s> ValidationIssuer<'s> for UpdatedSecuritySchemeCheck {
+    fn id(&self) -> &'static str {
+        "updated-security-scheme"
+    }
+
+    fn description(&self) -> &'static str {
+        "Security scheme type or location changed"
+    }
+
+    fn visitor(&self) -> &dyn DiffVisitor<'s> {
+        self
+    }
+
+    fn issues(&self) -> Option<Vec<ValidationIssue>> {
+        let pointers = std::mem::take(&mut *self.pointers.borrow_mut());
+
+        let issues = pointers
+            .into_iter()
+            .map(|(path, old_value, new_value)| {
+                ValidationIssue::new(path, self.id(), true)
+                    .with_values(old_value, new_value)
+            })
+            .collect::<Vec<ValidationIssue>>();
+
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::checker::updated_security_scheme_check::UpdatedSecuritySchemeCheck;
+    use crate::checker::ValidationIssuer;
+    use crate::get_schema_diff;
+    use crate::schema::HttpSchema;
+    use crate::schemas::openapi303::schema::OpenApi303;
+
+    #[test]
+    fn test_updated_security_scheme_check() {
+        let src_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security.json"
+            ))
+            .unwrap()
+            .into();
+
+        let tgt_schema: HttpSchema =
+            serde_json::from_str::<OpenApi303>(include_str!(
+                "../../data/checks/security/schema-with-security-altered.json"
+            ))
+            .unwrap()
+            .into();
+
+        let diff = get_schema_diff(src_schema, tgt_schema);
+
+        let checker = UpdatedSecuritySchemeCheck::default();
+        crate::visitor::dispatch_visitor(diff.get().unwrap(), &checker);
+        let issues = checker.issues().unwrap();
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues.first().unwrap().path.get_path(),
+            "components/securitySchemes/apiKey/in",
+        );
+        assert_eq!(issues[0].old_value, Some(json!("header")));
+        assert_eq!(issues[0].new_value, Some(json!("query")));
+        assert_eq!(
+            issues.get(1).unwrap().path.get_path(),
+            "components/securitySchemes/apiKey/name",
+        );
+        assert_eq!(issues[1].old_value, Some(json!("X-API-Key")));
+        assert_eq!(issues[1].new_value, Some(json!("api_key")));
+    }
+}
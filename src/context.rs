@@ -6,7 +6,7 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::core::{
-    ComponentContainer, DiffCache, DiffContext, DiffResult, MayBeRefCore,
+    deref_component, ComponentContainer, DiffCache, DiffContext, DiffResult,
 };
 use crate::schema::{
     Components, Example, Header, HttpSchema, Link, Parameter, Path,
@@ -216,121 +216,110 @@ pub fn deref_schema<'a>(
     components: &'a Option<Components>,
     reference: &str,
 ) -> Option<&'a Schema> {
-    components.as_ref().and_then(|components| {
-        components.schemas.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/schemas/", ""))
-                .map(|may_be_schema| match may_be_schema {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        components
+            .as_ref()
+            .and_then(|components| components.schemas.as_ref()),
+        "#/components/schemas/",
+        reference,
+    )
 }
 
 pub fn deref_parameter<'a>(
     components: &'a Option<Components>,
     reference: &str,
 ) -> Option<&'a Parameter> {
-    components.as_ref().and_then(|components| {
-        components.parameters.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/parameters/", ""))
-                .map(|may_be_parameter| match may_be_parameter {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        components
+            .as_ref()
+            .and_then(|components| components.parameters.as_ref()),
+        "#/components/parameters/",
+        reference,
+    )
 }
 
 pub fn deref_example<'a>(
     schema: &'a HttpSchema,
     reference: &str,
 ) -> Option<&'a Example> {
-    schema.components.as_ref().and_then(|components| {
-        components.examples.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/examples/", ""))
-                .map(|may_be_example| match may_be_example {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        schema
+            .components
+            .as_ref()
+            .and_then(|components| components.examples.as_ref()),
+        "#/components/examples/",
+        reference,
+    )
 }
 
 pub fn deref_request_body<'a>(
     schema: &'a HttpSchema,
     reference: &str,
 ) -> Option<&'a RequestBody> {
-    schema.components.as_ref().and_then(|components| {
-        components.request_bodies.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/requestBodies/", ""))
-                .map(|may_be_request_body| match may_be_request_body {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        schema
+            .components
+            .as_ref()
+            .and_then(|components| components.request_bodies.as_ref()),
+        "#/components/requestBodies/",
+        reference,
+    )
 }
 
 pub fn deref_header<'a>(
     schema: &'a HttpSchema,
     reference: &str,
 ) -> Option<&'a Header> {
-    schema.components.as_ref().and_then(|components| {
-        components.headers.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/headers/", ""))
-                .map(|may_be_header| match may_be_header {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        schema
+            .components
+            .as_ref()
+            .and_then(|components| components.headers.as_ref()),
+        "#/components/headers/",
+        reference,
+    )
 }
 
 pub fn deref_security_scheme<'a>(
     schema: &'a HttpSchema,
     reference: &str,
 ) -> Option<&'a SecurityScheme> {
-    schema.components.as_ref().and_then(|components| {
-        components.security_schemes.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/securitySchemes/", ""))
-                .map(|may_be_security_scheme| match may_be_security_scheme {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        schema
+            .components
+            .as_ref()
+            .and_then(|components| components.security_schemes.as_ref()),
+        "#/components/securitySchemes/",
+        reference,
+    )
 }
 
 pub fn deref_link<'a>(
     schema: &'a HttpSchema,
     reference: &str,
 ) -> Option<&'a Link> {
-    schema.components.as_ref().and_then(|components| {
-        components.links.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/links/", "")).map(
-                |may_be_link| match may_be_link {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                },
-            )
-        })
-    })
+    deref_component(
+        schema
+            .components
+            .as_ref()
+            .and_then(|components| components.links.as_ref()),
+        "#/components/links/",
+        reference,
+    )
 }
 
 pub fn deref_response<'a>(
     schema: &'a HttpSchema,
     reference: &str,
 ) -> Option<&'a Response> {
-    schema.components.as_ref().and_then(|components| {
-        components.responses.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/responses/", ""))
-                .map(|may_be_response| match may_be_response {
-                    MayBeRefCore::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        schema
+            .components
+            .as_ref()
+            .and_then(|components| components.responses.as_ref()),
+        "#/components/responses/",
+        reference,
+    )
 }
 
 impl ComponentContainer<Path> for HttpSchemaDiffContext {
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -35,9 +35,10 @@ pub trait DiffCache<O> {
     fn set_diff(&self, reference: &str, component: Arc<DiffResult<O>>);
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase", tag = "t", content = "v")]
 pub enum DiffResult<T> {
+    #[default]
     #[serde(rename = "n")]
     None,
     #[serde(rename = "=")]
@@ -110,6 +111,47 @@ impl<T> DiffResult<T> {
         }
     }
 
+    /// Value on the source side, `None` when unknown or absent
+    pub fn get_old(&self) -> Option<&T> {
+        match self {
+            DiffResult::Same(v) => Some(v),
+            DiffResult::Removed(v) => Some(v),
+            DiffResult::Updated(_, old) => old.as_deref(),
+            DiffResult::None | DiffResult::Added(_) => None,
+        }
+    }
+
+    /// Value on the target side
+    pub fn get_new(&self) -> Option<&T> {
+        match self {
+            DiffResult::Removed(_) => None,
+            _ => self.get(),
+        }
+    }
+
+    /// Value existing on the given side. Updated containers keep no old
+    /// value, their diff is returned for both sides.
+    pub fn get_side(&self, target: bool) -> Option<&T> {
+        match self {
+            DiffResult::None => None,
+            DiffResult::Added(v) => target.then_some(v),
+            DiffResult::Removed(v) => (!target).then_some(v),
+            DiffResult::Updated(new, Some(old)) if !target => Some(old),
+            DiffResult::Same(v) | DiffResult::Updated(v, _) => Some(v),
+        }
+    }
+
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        match self {
+            DiffResult::None => None,
+
+            DiffResult::Same(v) => Some(v),
+            DiffResult::Added(v) => Some(v),
+            DiffResult::Removed(v) => Some(v),
+            DiffResult::Updated(v, _) => Some(v),
+        }
+    }
+
     pub fn take(self) -> Option<T> {
         match self {
             DiffResult::None => None,
@@ -155,6 +197,18 @@ pub trait Empty {
 
 pub trait Keyed<C> {
     fn key(&self, c: C) -> String;
+
+    /// Whether the key is made up rather than taken from the item, such
+    /// items left unmatched on both sides of a list are matched by order
+    fn is_anonymous(&self) -> bool {
+        false
+    }
+
+    /// How close an anonymous item is to another one, leftovers are paired
+    /// by the closest match first, `0` when nothing but the order tells
+    fn similarity(&self, _other: &Self) -> usize {
+        0
+    }
 }
 
 impl<T, O, C: DiffContext> Diff<T, O, C> for Option<T>
@@ -198,6 +252,19 @@ pub enum EitherDiff<LD, RD> {
     ToLeft(Box<DiffResult<LD>>),
 }
 
+impl<LD, RD> DiffResult<EitherDiff<LD, RD>> {
+    /// Either diff of one side. Updates within a variant keep both sides in
+    /// the inner diff, values switched from are kept apart. Values switched
+    /// to are diffed against nothing, so they are read with `get`.
+    pub fn get_either(&self, target: bool) -> Option<&EitherDiff<LD, RD>> {
+        match self {
+            DiffResult::Updated(value, None) if !target => Some(value),
+            _ if target => self.get_new(),
+            _ => self.get_old(),
+        }
+    }
+}
+
 impl<LD, RD> Empty for EitherDiff<LD, RD> {
     fn is_empty(&self) -> bool {
         match self {
@@ -251,7 +318,23 @@ where
                 if diff.is_empty() {
                     DiffResult::Same(diff)
                 } else {
-                    DiffResult::Updated(diff, None)
+                    // Switching sides drops the old value, keep it here
+                    let old = match diff {
+                        EitherDiff::ToLeft(_) | EitherDiff::ToRight(_) => {
+                            Some(Box::new(match self {
+                                Either::Left(l) => EitherDiff::Left(
+                                    l.diff(None, &context.removing()),
+                                ),
+                                Either::Right(r) => {
+                                    EitherDiff::Right(Box::new(
+                                        r.diff(None, &context.removing()),
+                                    ))
+                                }
+                            }))
+                        }
+                        _ => None,
+                    };
+                    DiffResult::Updated(diff, old)
                 }
             }
         };
@@ -304,10 +387,61 @@ where
 
         match <T as Deserialize>::deserialize(de) {
             Ok(t) => Ok(MayBeRefCore::Value(t)),
-            Err(err) => {
-                eprintln!("Err path: {} --- {}", track.path(), &err);
-                Err(err)
-            }
+            Err(err) => Err(nest_error_path(track.path(), err)),
+        }
+    }
+}
+
+/// `MayBeRefCore` values are buffered before being decoded, so the path
+/// tracked by an outer `serde_path_to_error` deserializer stops at the
+/// value itself. The path inside the value is kept in the error message.
+fn nest_error_path<E: de::Error>(
+    path: serde_path_to_error::Path,
+    err: E,
+) -> E {
+    let path = path.to_string();
+    if path == "." {
+        err
+    } else {
+        E::custom(format_args!("`{path}`: {err}"))
+    }
+}
+
+/// Splits an error message into the nested paths added by
+/// `MayBeRefCore` deserialization and the actual error reason
+pub(crate) fn split_nested_error_path(message: &str) -> (Vec<&str>, &str) {
+    let mut paths = Vec::new();
+    let mut message = message;
+    while let Some(rest) = message.strip_prefix('`') {
+        let Some((path, rest)) = rest.split_once("`: ") else {
+            break;
+        };
+        paths.push(path);
+        message = rest;
+    }
+    (paths, message)
+}
+
+/// Looks up a component by reference, following refs to other components
+/// until a value is found. Returns `None` for dangling and cyclic refs.
+pub fn deref_component<'a, T, R: ReferenceDescriptor>(
+    components: Option<&'a IndexMap<String, MayBeRefCore<T, R>>>,
+    prefix: &str,
+    reference: &str,
+) -> Option<&'a T> {
+    let components = components?;
+
+    let mut visited = Vec::new();
+    let mut reference = reference;
+    loop {
+        if visited.contains(&reference) {
+            return None;
+        }
+        visited.push(reference);
+
+        match components.get(reference.strip_prefix(prefix)?)? {
+            MayBeRefCore::Value(value) => return Some(value),
+            MayBeRefCore::Ref(value) => reference = value.reference(),
         }
     }
 }
@@ -359,6 +493,22 @@ where
             MayBeRefCore::Ref(value) => value.reference().to_owned(),
         }
     }
+
+    fn is_anonymous(&self) -> bool {
+        match self {
+            MayBeRefCore::Value(value) => value.is_anonymous(),
+            MayBeRefCore::Ref(_) => false,
+        }
+    }
+
+    fn similarity(&self, other: &Self) -> usize {
+        match (self, other) {
+            (MayBeRefCore::Value(value), MayBeRefCore::Value(other)) => {
+                value.similarity(other)
+            }
+            _ => 0,
+        }
+    }
 }
 
 impl<T, O, R, C: DiffContext>
@@ -539,12 +689,16 @@ where
     }
 }
 
+// Security requirements are identified by the set of their schemes
 impl<V> Keyed<usize> for IndexMap<String, V> {
-    fn key(&self, idx: usize) -> String {
-        idx.to_string()
+    fn key(&self, _: usize) -> String {
+        let mut names: Vec<_> = self.keys().collect();
+        names.sort();
+        format!("{names:?}")
     }
 }
 
+#[allow(clippy::ptr_arg)]
 pub trait PathResolver {
     /// Object implementing this trait can determine that two different
     /// keys from src and tgt represents the same object and thus must be mapped
@@ -677,8 +831,12 @@ impl<T> VecDiffTransformer<T> for DefaultVecDiffTransformer {
 pub struct VecDiff<T, S = DefaultVecDiffTransformer>(
     pub Vec<DiffResult<T>>,
     #[serde(skip)] PhantomData<S>,
+    #[serde(skip)] Vec<VecDiffPosition>,
 );
 
+/// Positions of an item in the source and target lists
+pub type VecDiffPosition = (Option<usize>, Option<usize>);
+
 impl<T, S> Deref for VecDiff<T, S> {
     type Target = Vec<DiffResult<T>>;
 
@@ -687,10 +845,71 @@ impl<T, S> Deref for VecDiff<T, S> {
     }
 }
 
+impl<T, S> VecDiff<T, S> {
+    /// Items with their position in the target list, or in the source list
+    /// for removed items. Positions are not serialized, so diffs read back
+    /// fall back to the order of items.
+    pub fn positioned(
+        &self,
+    ) -> impl Iterator<Item = (usize, &DiffResult<T>)> + '_ {
+        self.0.iter().enumerate().map(|(idx, item)| {
+            let position = self.2.get(idx).and_then(|(old, new)| new.or(*old));
+            (position.unwrap_or(idx), item)
+        })
+    }
+
+    /// Items existing on one side, in the order of that side's list
+    pub fn side(&self, target: bool) -> Vec<&T> {
+        let mut items: Vec<_> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                if target {
+                    !item.is_removed()
+                } else {
+                    !item.is_added()
+                }
+            })
+            .filter_map(|(idx, item)| {
+                let position = self.2.get(idx).and_then(|(old, new)| {
+                    if target {
+                        new.or(*old)
+                    } else {
+                        old.or(*new)
+                    }
+                });
+                Some((position.unwrap_or(idx), item.get()?))
+            })
+            .collect();
+
+        items.sort_by_key(|(position, _)| *position);
+        items.into_iter().map(|(_, item)| item).collect()
+    }
+}
+
+/// Items by key with their position, repeated keys are numbered
+fn keyed<T: Keyed<usize>>(items: &[T]) -> IndexMap<String, (usize, &T)> {
+    let mut map = IndexMap::with_capacity(items.len());
+
+    for (idx, item) in items.iter().enumerate() {
+        let key = item.key(idx);
+        let mut unique = key.clone();
+        let mut repeat = 1;
+        while map.contains_key(&unique) {
+            repeat += 1;
+            unique = format!("{key}#{repeat}");
+        }
+        map.insert(unique, (idx, item));
+    }
+
+    map
+}
+
 impl<T, O, C: DiffContext, S> Diff<Vec<T>, VecDiff<O, S>, C> for Vec<T>
 where
     T: Diff<T, O, C> + Keyed<usize> + Debug,
-    S: VecDiffTransformer<Vec<DiffResult<O>>>,
+    S: VecDiffTransformer<Vec<(DiffResult<O>, VecDiffPosition)>>,
     O: Debug,
 {
     fn diff(
@@ -702,67 +921,119 @@ where
             None => DiffResult::Removed(VecDiff(
                 self.iter().map(|x| x.diff(None, context)).collect(),
                 PhantomData,
+                (0..self.len()).map(|idx| (Some(idx), None)).collect(),
             )),
             Some(value) => {
-                let o: IndexMap<_, _> = self
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, v)| (v.key(idx), v))
+                let o = keyed(self);
+                let n = keyed(value);
+
+                let o_keys: IndexSet<_> = o.keys().collect();
+                let n_keys: IndexSet<_> = n.keys().collect();
+
+                let mut new_keys: Vec<_> =
+                    n_keys.difference(&o_keys).copied().collect();
+                let mut removed_keys: Vec<_> =
+                    o_keys.difference(&n_keys).copied().collect();
+                let mut updated_keys: Vec<_> = o_keys
+                    .intersection(&n_keys)
+                    .map(|key| (*key, *key))
                     .collect();
 
-                let n: IndexMap<_, _> = value
+                // Items without identity left on both sides are paired by
+                // the closest match, then by their order
+                let is_anonymous =
+                    |map: &IndexMap<String, (usize, &T)>, key: &String| {
+                        map[key].1.is_anonymous()
+                    };
+                let mut anonymous_removed: Vec<_> = removed_keys
                     .iter()
-                    .enumerate()
-                    .map(|(idx, v)| (v.key(idx), v))
+                    .copied()
+                    .filter(|key| is_anonymous(&o, key))
+                    .collect();
+                let mut anonymous_added: Vec<_> = new_keys
+                    .iter()
+                    .copied()
+                    .filter(|key| is_anonymous(&n, key))
                     .collect();
 
-                let o_keys: IndexSet<_> = o.keys().collect();
-                let n_keys: IndexSet<_> = n.keys().collect();
-
-                let new_keys: IndexSet<_> =
-                    n_keys.difference(&o_keys).collect();
-                let removed_keys: IndexSet<_> =
-                    o_keys.difference(&n_keys).collect();
-                let updated_keys: IndexSet<_> =
-                    o_keys.intersection(&n_keys).collect();
+                let (o_ref, n_ref) = (&o, &n);
+                let mut candidates: Vec<_> = anonymous_removed
+                    .iter()
+                    .flat_map(|old_key| {
+                        anonymous_added.iter().map(move |new_key| {
+                            let (old_idx, old) = o_ref[*old_key];
+                            let (new_idx, new) = n_ref[*new_key];
+                            let score = old.similarity(new);
+                            let distance = old_idx.abs_diff(new_idx);
+                            (score, distance, *old_key, *new_key)
+                        })
+                    })
+                    .filter(|(score, ..)| *score > 0)
+                    .collect();
+                candidates.sort_by_key(|(score, distance, ..)| {
+                    (std::cmp::Reverse(*score), *distance)
+                });
+
+                let mut pairs = Vec::new();
+                for (_, _, old_key, new_key) in candidates {
+                    if anonymous_removed.contains(&old_key)
+                        && anonymous_added.contains(&new_key)
+                    {
+                        anonymous_removed.retain(|key| *key != old_key);
+                        anonymous_added.retain(|key| *key != new_key);
+                        pairs.push((old_key, new_key));
+                    }
+                }
+                pairs.extend(
+                    anonymous_removed.into_iter().zip(anonymous_added),
+                );
+
+                for (old_key, new_key) in pairs {
+                    removed_keys.retain(|key| *key != old_key);
+                    new_keys.retain(|key| *key != new_key);
+                    updated_keys.push((old_key, new_key));
+                }
 
                 let added: Vec<_> = new_keys
                     .into_iter()
                     .map(|key| {
-                        let new = n.get(*key).expect("key must present");
-                        None.diff(Some(*new), context)
+                        let (idx, new) = n.get(key).expect("key must present");
+                        (None.diff(Some(*new), context), (None, Some(*idx)))
                     })
                     .collect();
 
                 let removed: Vec<_> = removed_keys
                     .into_iter()
                     .map(|key| {
-                        let old = o.get(*key).expect("key must present");
-                        old.diff(None, context)
+                        let (idx, old) = o.get(key).expect("key must present");
+                        (old.diff(None, context), (Some(*idx), None))
                     })
                     .collect();
 
                 let updated: Vec<_> = updated_keys
                     .into_iter()
-                    .map(|key| {
-                        let old = o.get(*key).expect("key must present");
-                        let new = n.get(*key).expect("key must present");
-                        old.diff(Some(*new), context)
+                    .map(|(old_key, new_key)| {
+                        let (old_idx, old) =
+                            o.get(old_key).expect("key must present");
+                        let (new_idx, new) =
+                            n.get(new_key).expect("key must present");
+                        (
+                            old.diff(Some(*new), context),
+                            (Some(*old_idx), Some(*new_idx)),
+                        )
                     })
                     .collect();
 
-                let values: Vec<_> = added
-                    .into_iter()
-                    .chain(updated.into_iter())
-                    .chain(removed.into_iter())
-                    .collect();
+                let values: Vec<_> =
+                    added.into_iter().chain(updated).chain(removed).collect();
 
                 let is_same =
-                    values.iter().all(|value| value.is_same_or_none());
+                    values.iter().all(|(value, _)| value.is_same_or_none());
 
-                let values = S::transform(values);
+                let (values, positions) =
+                    S::transform(values).into_iter().unzip();
 
-                let diff = VecDiff(values, PhantomData);
+                let diff = VecDiff(values, PhantomData, positions);
 
                 if is_same {
                     DiffResult::Same(diff)
@@ -808,7 +1079,7 @@ impl Keyed<usize> for Value {
     }
 }
 
-impl<'a> Keyed<usize> for &'a str {
+impl Keyed<usize> for &str {
     fn key(&self, _: usize) -> String {
         self.to_string()
     }
@@ -0,0 +1,278 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::schema::HttpSchema;
+
+/// Problem found in a schema which does not prevent it from being diffed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// Schema the problem was found in, set once the schemas are diffed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<DiagnosticDocument>,
+    /// JSON pointer to the problem location within the converted schema
+    pub path: String,
+    pub reference: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticKind {
+    /// `$ref` points to nothing, either directly or through other refs
+    UnresolvedReference,
+    /// `$ref` chain leads back to one of its own refs
+    CyclicReference,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticDocument {
+    Source,
+    Target,
+}
+
+impl Diagnostic {
+    pub fn with_document(mut self, document: DiagnosticDocument) -> Self {
+        self.document = Some(document);
+        self
+    }
+}
+
+/// Keywords holding arbitrary user values which may look like refs
+const DATA_KEYS: [&str; 5] = ["const", "default", "enum", "example", "value"];
+
+/// Keys of maps whose own keys are user-given names, e.g. property names,
+/// rather than keywords
+const NAMED_MAPS: [&str; 20] = [
+    "$defs",
+    "callbacks",
+    "content",
+    "definitions",
+    "dependentSchemas",
+    "encoding",
+    "examples",
+    "headers",
+    "links",
+    "parameters",
+    "pathItems",
+    "paths",
+    "patternProperties",
+    "properties",
+    "requestBodies",
+    "responses",
+    "schemas",
+    "securitySchemes",
+    "variables",
+    "webhooks",
+];
+
+/// Whether the keyword holds user values, whose refs are not followed
+pub(crate) fn is_data_keyword(key: &str, value: &Value) -> bool {
+    DATA_KEYS.contains(&key)
+        || key.starts_with("x-")
+        // Schema `examples` is a list of values, unlike the map of
+        // example objects used by media types and parameters
+        || (key == "examples" && value.is_array())
+}
+
+/// Whether the keyword is a map of user-given names
+pub(crate) fn is_named_map(key: &str) -> bool {
+    NAMED_MAPS.contains(&key)
+}
+
+/// Finds refs which cannot be followed to a value within the schema
+pub fn check_references(schema: &HttpSchema) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if let Ok(root) = serde_json::to_value(schema) {
+        check_node(&root, &root, false, &mut String::new(), &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+/// Checks refs of the `node`, which is a map of user-given names when
+/// `named` is set
+fn check_node(
+    root: &Value,
+    node: &Value,
+    named: bool,
+    path: &mut String,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    match node {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                if let Some(kind) = resolve(root, reference) {
+                    diagnostics.push(Diagnostic {
+                        kind,
+                        document: None,
+                        path: path.clone(),
+                        reference: reference.clone(),
+                    });
+                }
+                return;
+            }
+
+            for (key, value) in map {
+                let is_keyword = !named;
+                if is_keyword && is_data_keyword(key, value) {
+                    continue;
+                }
+                let is_named = is_keyword && is_named_map(key);
+                let len = path.len();
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                check_node(root, value, is_named, path, diagnostics);
+                path.truncate(len);
+            }
+        }
+        Value::Array(values) => {
+            for (idx, value) in values.iter().enumerate() {
+                let len = path.len();
+                path.push('/');
+                path.push_str(&idx.to_string());
+                check_node(root, value, false, path, diagnostics);
+                path.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Follows the ref chain, returning the problem if it has one
+fn resolve(root: &Value, reference: &str) -> Option<DiagnosticKind> {
+    let mut visited = Vec::new();
+    let mut reference = reference;
+    loop {
+        if visited.contains(&reference) {
+            return Some(DiagnosticKind::CyclicReference);
+        }
+        visited.push(reference);
+
+        let Some(value) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            return Some(DiagnosticKind::UnresolvedReference);
+        };
+
+        match value.get("$ref") {
+            Some(Value::String(next)) => reference = next,
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::openapi303::schema::OpenApi303;
+    use crate::{get_schema_diff, try_deserialize_schema};
+
+    #[test]
+    fn test_check_references() {
+        let content = r##"{
+            "openapi": "3.0.3",
+            "paths": {"/users": {"get": {
+                "parameters": [
+                    {"$ref": "#/components/parameters/Missing"},
+                    {"$ref": "#/components/parameters/Limit"}
+                ],
+                "responses": {"200": {
+                    "description": "",
+                    "content": {"application/json": {
+                        "schema": {"$ref": "#/components/schemas/A"},
+                        "example": {"$ref": "#/not/a/ref"}
+                    }}
+                }}
+            }}},
+            "components": {
+                "schemas": {
+                    "A": {"$ref": "#/components/schemas/B"},
+                    "B": {"$ref": "#/components/schemas/A"}
+                },
+                "parameters": {
+                    "Limit": {"$ref": "#/components/parameters/Size"},
+                    "Size": {"name": "size", "in": "query"}
+                }
+            }
+        }"##;
+
+        let schema = serde_json::from_str::<OpenApi303>(content).unwrap();
+        let diagnostics = HttpSchema::from(schema).diagnostics;
+
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.kind, d.path.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (DiagnosticKind::CyclicReference, "/components/schemas/A"),
+                (DiagnosticKind::CyclicReference, "/components/schemas/B"),
+                (
+                    DiagnosticKind::UnresolvedReference,
+                    "/paths/~1users/get/parameters/0"
+                ),
+                (
+                    DiagnosticKind::CyclicReference,
+                    "/paths/~1users/get/responses/200/content/\
+                     application~1json/schema"
+                ),
+            ]
+        );
+
+        let (src, tgt) = try_deserialize_schema(content, content).unwrap();
+        let diff = get_schema_diff(src, tgt);
+        let diagnostics = &diff.get().unwrap().diagnostics;
+        assert_eq!(diagnostics.len(), 8);
+        assert_eq!(diagnostics[0].document, Some(DiagnosticDocument::Source));
+        assert_eq!(diagnostics[7].document, Some(DiagnosticDocument::Target));
+    }
+
+    #[test]
+    fn test_check_references_named_like_keywords() {
+        let content = r##"{
+            "openapi": "3.0.3",
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Setting": {
+                        "type": "object",
+                        "properties": {
+                            "value": {"$ref": "#/components/schemas/Missing"},
+                            "default": {
+                                "type": "string",
+                                "default": {"$ref": "#/not/a/ref"}
+                            }
+                        }
+                    },
+                    "value": {"$ref": "#/components/schemas/Gone"}
+                }
+            }
+        }"##;
+
+        let schema = serde_json::from_str::<OpenApi303>(content).unwrap();
+        let diagnostics = HttpSchema::from(schema).diagnostics;
+
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.kind, d.path.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    DiagnosticKind::UnresolvedReference,
+                    "/components/schemas/Setting/properties/value"
+                ),
+                (
+                    DiagnosticKind::UnresolvedReference,
+                    "/components/schemas/value"
+                ),
+            ]
+        );
+    }
+}
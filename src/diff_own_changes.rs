@@ -3,20 +3,20 @@ use crate::diff_result_type::DiffResultType;
 use std::borrow::Cow;
 
 pub trait DiffOwnChanges {
-    fn get_own_changes(&self) -> Vec<(Cow<str>, DiffResultType)>;
+    fn get_own_changes(&self) -> Vec<(Cow<'_, str>, DiffResultType)>;
 }
 
 impl<T> DiffOwnChanges for DiffResult<T>
 where
     T: DiffOwnChanges,
 {
-    fn get_own_changes(&self) -> Vec<(Cow<str>, DiffResultType)> {
+    fn get_own_changes(&self) -> Vec<(Cow<'_, str>, DiffResultType)> {
         self.get().map_or(vec![], |v| v.get_own_changes())
     }
 }
 
 impl<T> DiffOwnChanges for VecDiff<T> {
-    fn get_own_changes(&self) -> Vec<(Cow<str>, DiffResultType)> {
+    fn get_own_changes(&self) -> Vec<(Cow<'_, str>, DiffResultType)> {
         self.0
             .iter()
             .enumerate()
@@ -32,7 +32,7 @@ impl<T> DiffOwnChanges for VecDiff<T> {
 }
 
 impl<T, R> DiffOwnChanges for MapDiff<T, R> {
-    fn get_own_changes(&self) -> Vec<(Cow<str>, DiffResultType)> {
+    fn get_own_changes(&self) -> Vec<(Cow<'_, str>, DiffResultType)> {
         self.0
             .iter()
             .filter_map(|(key, e)| {
@@ -1,6 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use crate::core::DiffResult;
 
-#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum DiffResultType {
     None,
     Same,
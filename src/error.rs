@@ -5,11 +5,73 @@ use thiserror::Error;
 /// errors that openapi functions may return
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Source schema JSON serialization error")]
-    InvalidSourceSchema,
-    #[error("Target schema JSON serialization error")]
-    InvalidTargetSchema,
+    #[error("Source schema deserialization error: {0}")]
+    InvalidSourceSchema(SchemaError),
+    #[error("Target schema deserialization error: {0}")]
+    InvalidTargetSchema(SchemaError),
 
     #[error("I/O error")]
     Io(#[from] IoError),
 }
+
+/// Reason a schema document could not be deserialized
+#[derive(Error, Debug)]
+pub enum SchemaError {
+    #[error("malformed document: {reason}")]
+    Malformed {
+        reason: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    #[error("schema version not found: no `openapi` or `swagger` field")]
+    UnknownVersion,
+    #[error("unsupported schema version `{0}`")]
+    UnsupportedVersion(String),
+    #[error(transparent)]
+    Reference(#[from] ReferenceError),
+    #[error(
+        "{decoder} decoder failed at `{path}`: {reason}{}",
+        display_location(*line, *column)
+    )]
+    Invalid {
+        /// Decoder chosen by the document version field
+        decoder: &'static str,
+        /// Path to the invalid value, e.g. `paths./pets.get.responses`
+        path: String,
+        reason: String,
+        /// Position of the invalid value in the document text. Values
+        /// inlined from external refs are reported at the `$ref` position.
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+}
+
+fn display_location(line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => {
+            format!(" (line {line}, column {column})")
+        }
+        (Some(line), None) => format!(" (line {line})"),
+        _ => String::new(),
+    }
+}
+
+/// Reason an external `$ref` could not be resolved
+#[derive(Error, Debug)]
+pub enum ReferenceError {
+    #[error("unable to load `{document}`: {reason}")]
+    Load { document: String, reason: String },
+    #[error("unable to parse `{document}`: {reason}")]
+    Parse { document: String, reason: String },
+    #[error("reference `{0}` not found")]
+    NotFound(String),
+    #[error("reference loop: {}", .0.join(" -> "))]
+    Loop(Vec<String>),
+}
+
+/// Reason a validation config could not be applied
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("unknown rules: {}", .0.join(", "))]
+    UnknownRules(Vec<String>),
+}
@@ -2,11 +2,14 @@ use indexmap::IndexMap;
 use std::cell::RefCell;
 
 use crate::core::DiffResult;
-use crate::exporters::{display_method, display_uri, Exporter, Markdown};
+use crate::exporters::{
+    display_callback, display_method, display_uri, Exporter, Markdown,
+};
 
-use crate::checker::ValidationIssue;
-use crate::path_pointer::PathPointer;
-use crate::schema_diff::{HttpSchemaDiff, OperationDiff};
+use crate::checker::{Severity, ValidationIssue};
+use crate::core::MapDiff;
+use crate::path_pointer::{PathPointer, PathPointerScope};
+use crate::schema_diff::{CallbackDiff, HttpSchemaDiff, OperationDiff};
 
 use crate::visitor::{dispatch_visitor, DiffVisitor};
 
@@ -15,11 +18,19 @@ struct PathToMarkdownVisitor<'s, 'v> {
     endpoints: Option<&'v [String]>,
     validations: Option<&'v [ValidationIssue]>,
 
-    added: RefCell<Vec<(PathPointer, &'s OperationDiff, bool)>>,
-    updated: RefCell<Vec<(PathPointer, &'s OperationDiff, bool)>>,
-    removed: RefCell<Vec<(PathPointer, &'s OperationDiff, bool)>>,
+    added: RefCell<Vec<ExportedOperation<'s, 'v>>>,
+    updated: RefCell<Vec<ExportedOperation<'s, 'v>>>,
+    removed: RefCell<Vec<ExportedOperation<'s, 'v>>>,
 }
 
+/// Operation with whether it breaks and the issues reported inside it
+type ExportedOperation<'s, 'v> = (
+    PathPointer,
+    &'s OperationDiff,
+    bool,
+    Vec<&'v ValidationIssue>,
+);
+
 impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
     fn visit_operation(
         &self,
@@ -27,7 +38,9 @@ impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
         _method: &str,
         operation_diff_result: &'s DiffResult<OperationDiff>,
     ) -> bool {
-        if let Some(endpoints) = self.endpoints {
+        // Callbacks are only visited when their operation passed the filter
+        let is_callback = pointer.is_in(PathPointerScope::Callbacks);
+        if let Some(endpoints) = self.endpoints.filter(|_| !is_callback) {
             if !endpoints.is_empty() {
                 let is_matches =
                     endpoints.iter().any(|filter| pointer.matches(filter));
@@ -38,6 +51,7 @@ impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
         }
 
         let mut has_breaking = false;
+        let mut issues = Vec::new();
         if let Some(validations) = self.validations {
             let is_invalid = validations
                 .iter()
@@ -47,8 +61,24 @@ impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
             }
 
             has_breaking = validations.iter().any(|validation| {
-                validation.path.startswith(pointer) && validation.breaking
+                validation.path.startswith(pointer)
+                    && validation.breaking
+                    && !validation.is_suppressed()
             });
+
+            // Issues of callbacks are listed under the callback operations
+            let is_callback = pointer.is_in(PathPointerScope::Callbacks);
+            issues = validations
+                .iter()
+                .filter(|validation| {
+                    validation.path.startswith(pointer)
+                        && !validation.is_suppressed()
+                        && (is_callback
+                            || !validation
+                                .path
+                                .is_in(PathPointerScope::Callbacks))
+                })
+                .collect();
         }
 
         match operation_diff_result {
@@ -59,6 +89,7 @@ impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
                     pointer.clone(),
                     value,
                     has_breaking,
+                    issues,
                 ));
             }
             DiffResult::Updated(value, _) => {
@@ -66,6 +97,7 @@ impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
                     pointer.clone(),
                     value,
                     has_breaking,
+                    issues,
                 ));
             }
             DiffResult::Removed(value) => {
@@ -73,11 +105,20 @@ impl<'s, 'v> DiffVisitor<'s> for PathToMarkdownVisitor<'s, 'v> {
                     pointer.clone(),
                     value,
                     has_breaking,
+                    issues,
                 ));
             }
         };
 
-        false
+        operation_diff_result.is_updated()
+    }
+
+    fn visit_callbacks(
+        &self,
+        pointer: &PathPointer,
+        _: &'s DiffResult<MapDiff<CallbackDiff>>,
+    ) -> bool {
+        pointer.is_updated()
     }
 }
 
@@ -121,24 +162,27 @@ impl Exporter<Markdown> for HttpSchemaDiff {
             markdown.push_str(&format!("Generated at: *{now} UTC*\n"));
         }
 
-        if added.len() > 0 {
+        if !added.is_empty() {
             markdown.push_str(&format!("\n*Added ({})*\n", added.len()));
-            for (path, _, breaking) in added.iter() {
+            for (path, _, breaking, issues) in added.iter() {
                 markdown.push_str(&format_path(path, *breaking, version_url));
+                markdown.push_str(&format_issues(path, issues));
             }
         }
 
-        if updated.len() > 0 {
+        if !updated.is_empty() {
             markdown.push_str(&format!("\n*Updated ({})*\n", updated.len()));
-            for (path, _, breaking) in updated.iter() {
+            for (path, _, breaking, issues) in updated.iter() {
                 markdown.push_str(&format_path(path, *breaking, version_url));
+                markdown.push_str(&format_issues(path, issues));
             }
         }
 
-        if removed.len() > 0 {
+        if !removed.is_empty() {
             markdown.push_str(&format!("\n*Removed ({})*\n", removed.len()));
-            for (path, _, breaking) in removed.iter() {
+            for (path, _, breaking, issues) in removed.iter() {
                 markdown.push_str(&format_path(path, *breaking, version_url));
+                markdown.push_str(&format_issues(path, issues));
             }
         }
 
@@ -157,6 +201,160 @@ fn format_path(
 
     let method = display_method(path).to_uppercase();
     let uri = display_uri(path);
+    let callback = display_callback(path)
+        .map_or_else(String::new, |callback| format!(" {callback}"));
+
+    format!(" {breaking} `{method:^8}` `{uri}`{callback} <{url}|view>\n")
+}
+
+/// Lines explaining the issues of an operation, below its own line
+fn format_issues(path: &PathPointer, issues: &[&ValidationIssue]) -> String {
+    let operation = path.get_path();
+
+    issues
+        .iter()
+        .map(|issue| {
+            let severity = match issue.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "info",
+            };
+            let message = if issue.message.is_empty() {
+                issue.kind.as_ref()
+            } else {
+                issue.message.as_str()
+            };
+
+            let issue_path = issue.path.get_path();
+            let location = issue_path
+                .strip_prefix(&operation)
+                .map(|location| location.trim_start_matches('/'))
+                .filter(|location| !location.is_empty())
+                .map_or_else(String::new, |location| format!(" `{location}`"));
+
+            format!("     • _{severity}_{location} {message}\n")
+        })
+        .collect()
+}
 
-    format!(" {breaking} `{method:^8}` `{uri}` <{url}|view>\n")
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::checker::validate;
+    use crate::exporters::Exporter;
+    use crate::{get_schema_diff, try_deserialize_schema};
+
+    #[test]
+    fn test_export_callbacks() {
+        let schema = |event_type: &str| {
+            format!(
+                r##"{{
+                "openapi": "3.0.3",
+                "paths": {{"/subscribe": {{"post": {{
+                    "responses": {{"201": {{"description": ""}}}},
+                    "callbacks": {{
+                        "onEvent": {{"{{$request.body#/url}}": {{"post": {{
+                            "requestBody": {{"content": {{"application/json": {{
+                                "schema": {{"type": "{event_type}"}}
+                            }}}}}},
+                            "responses": {{"200": {{"description": ""}}}}
+                        }}}}}},
+                        "onClose": {{"$ref": "#/components/callbacks/Close"}}
+                    }}
+                }}}}}},
+                "components": {{"callbacks": {{"Close": {{
+                    "{{$request.body#/url}}/close": {{"delete": {{
+                        "responses": {{"200": {{"description": ""}}}}
+                    }}}}
+                }}}}}}
+            }}"##
+            )
+        };
+
+        let (src, tgt) =
+            try_deserialize_schema(&schema("object"), &schema("string"))
+                .unwrap();
+
+        let operation = src.paths.as_ref().unwrap()["/subscribe"]
+            .value()
+            .unwrap()
+            .post
+            .as_ref()
+            .unwrap();
+        let callbacks = operation.callbacks.as_ref().unwrap();
+        assert!(callbacks["onClose"]["{$request.body#/url}/close"]
+            .value()
+            .unwrap()
+            .delete
+            .is_some());
+
+        let diff = get_schema_diff(src, tgt);
+        let markdown =
+            diff.get()
+                .unwrap()
+                .export(IndexMap::new(), "", false, None, None);
+
+        let lines: Vec<_> = markdown
+            .as_str()
+            .lines()
+            .filter(|line| line.starts_with(" - "))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                " - `  POST  ` `/subscribe` \
+                 <#paths//subscribe/post|view>",
+                " - `  POST  ` `/subscribe` \
+                 callback `onEvent` `POST` `{$request.body#/url}` \
+                 <#paths//subscribe/post/callbacks/onEvent/\
+                 {$request.body#/url}/post|view>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_issues() {
+        let schema = |max_length: usize| {
+            format!(
+                r#"{{
+                "openapi": "3.0.3",
+                "paths": {{"/pets": {{"post": {{
+                    "requestBody": {{"content": {{"application/json": {{
+                        "schema": {{"type": "string", "maxLength": {max_length}}}
+                    }}}}}},
+                    "responses": {{"201": {{"description": ""}}}}
+                }}}}}}
+            }}"#
+            )
+        };
+
+        let (src, tgt) =
+            try_deserialize_schema(&schema(50), &schema(20)).unwrap();
+
+        let diff = get_schema_diff(src, tgt);
+        let issues = validate(diff.get().unwrap(), &["*"]);
+        let markdown = diff.get().unwrap().export(
+            IndexMap::new(),
+            "",
+            false,
+            None,
+            Some(&issues),
+        );
+
+        let lines: Vec<_> = markdown
+            .as_str()
+            .lines()
+            .skip_while(|line| !line.starts_with(" ! "))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                " ! `  POST  ` `/pets` <#paths//pets/post|view>",
+                "     • _error_ \
+                 `requestBody/content/application/json/schema/maxLength` \
+                 Maximum string length changed: `50` changed to `20`",
+            ]
+        );
+    }
 }
@@ -65,3 +65,21 @@ pub fn display_method(pointer: &PathPointer) -> String {
         "".to_string()
     }
 }
+
+/// Describes the callback operation at the pointer,
+/// e.g. "callback `onEvent` `POST` `{$request.body#/url}`"
+pub fn display_callback(pointer: &PathPointer) -> Option<String> {
+    let latest = |scope: PathPointerScope| {
+        pointer
+            .components
+            .iter()
+            .rfind(|c| c.scope.as_ref() == Some(&scope))
+            .and_then(|c| c.path.as_ref())
+    };
+
+    let name = latest(PathPointerScope::Callback)?;
+    let expression = latest(PathPointerScope::Path)?;
+    let method = latest(PathPointerScope::Operation)?.to_uppercase();
+
+    Some(format!("callback `{name}` `{method}` `{expression}`"))
+}
@@ -1,10 +1,12 @@
 pub mod checker;
 pub mod context;
 pub mod core;
+pub mod diagnostic;
 pub mod diff_own_changes;
 pub mod diff_result_type;
 pub mod error;
 pub mod exporters;
+pub mod loader;
 pub mod path_pointer;
 pub mod schema;
 pub mod schema_diff;
@@ -15,16 +17,23 @@ pub mod visitors;
 
 use crate::context::HttpSchemaDiffContext;
 use crate::core::{Diff, DiffResult};
+use crate::diagnostic::DiagnosticDocument;
 use once_cell::sync::Lazy;
 use std::rc::Rc;
 use tracing::info;
 
-use crate::error::Error;
+use crate::core::split_nested_error_path;
+use crate::error::{Error, SchemaError};
+use crate::loader::ReferenceLoader;
 use crate::schema::HttpSchema;
 use crate::schema_diff::HttpSchemaDiff;
+use crate::schemas::de::Locate;
 use crate::schemas::openapi303::schema::OpenApi303;
 use crate::schemas::openapi310::schema::OpenApi310;
 use crate::schemas::swagger2::schema::SwaggerV2;
+use serde::de::{DeserializeOwned, DeserializeSeed};
+use serde::Deserialize;
+use serde_json::Value;
 
 use crate::schemas::openapi303::converter::VERSION as OPENAPI303_CONVERTER_VERSION;
 use crate::schemas::openapi310::converter::VERSION as OPENAPI310_CONVERTER_VERSION;
@@ -58,41 +67,98 @@ pub fn is_current_diff_version(diff_version: &str) -> bool {
     VERSIONS.iter().any(|x| x == diff_version)
 }
 
-#[tracing::instrument(skip_all, fields(src.schema.decoder, src.schema.version, tgt.schema.decoder, tgt.schema.version))]
+/// Serialization format of an input schema document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaFormat {
+    Json,
+    Yaml,
+}
+
+impl SchemaFormat {
+    /// Guesses the format by the first meaningful character of the content
+    pub fn detect(content: &str) -> Self {
+        let content = content.trim_start_matches('\u{feff}').trim_start();
+        if content.starts_with('{') || content.starts_with('[') {
+            SchemaFormat::Json
+        } else {
+            SchemaFormat::Yaml
+        }
+    }
+}
+
+/// Deserializes JSON source and target schemas
 pub fn try_deserialize_schema(
     src_content: &str,
     tgt_content: &str,
 ) -> Result<(schema::HttpSchema, schema::HttpSchema), Error> {
-    let source: schema::HttpSchema = if let Ok(schema) =
-        serde_json::from_str::<OpenApi310>(src_content)
-    {
-        Ok(schema.into())
-    } else if let Ok(schema) = serde_json::from_str::<OpenApi303>(src_content)
-    {
-        Ok(schema.into())
-    } else if let Ok(schema) = serde_json::from_str::<SwaggerV2>(src_content) {
-        Ok(schema.into())
-    } else {
-        Err(Error::InvalidSourceSchema)
-    }?;
+    deserialize_schemas(
+        (src_content, SchemaFormat::Json, None),
+        (tgt_content, SchemaFormat::Json, None),
+    )
+}
+
+/// Deserializes YAML source and target schemas
+pub fn try_deserialize_yaml_schema(
+    src_content: &str,
+    tgt_content: &str,
+) -> Result<(schema::HttpSchema, schema::HttpSchema), Error> {
+    deserialize_schemas(
+        (src_content, SchemaFormat::Yaml, None),
+        (tgt_content, SchemaFormat::Yaml, None),
+    )
+}
+
+/// Deserializes source and target schemas detecting the format of each
+pub fn try_deserialize_any_schema(
+    src_content: &str,
+    tgt_content: &str,
+) -> Result<(schema::HttpSchema, schema::HttpSchema), Error> {
+    deserialize_schemas(
+        (src_content, SchemaFormat::detect(src_content), None),
+        (tgt_content, SchemaFormat::detect(tgt_content), None),
+    )
+}
+
+/// Deserializes source and target schemas detecting the format of each
+/// and inlining external `$ref`s with documents from the given loaders
+pub fn try_deserialize_schema_with_loaders(
+    src_content: &str,
+    src_loader: &dyn ReferenceLoader,
+    tgt_content: &str,
+    tgt_loader: &dyn ReferenceLoader,
+) -> Result<(schema::HttpSchema, schema::HttpSchema), Error> {
+    deserialize_schemas(
+        (
+            src_content,
+            SchemaFormat::detect(src_content),
+            Some(src_loader),
+        ),
+        (
+            tgt_content,
+            SchemaFormat::detect(tgt_content),
+            Some(tgt_loader),
+        ),
+    )
+}
+
+type SchemaInput<'a> =
+    (&'a str, SchemaFormat, Option<&'a dyn ReferenceLoader>);
+
+#[tracing::instrument(skip_all, fields(src.schema.decoder, src.schema.version, tgt.schema.decoder, tgt.schema.version))]
+fn deserialize_schemas(
+    (src_content, src_format, src_loader): SchemaInput,
+    (tgt_content, tgt_format, tgt_loader): SchemaInput,
+) -> Result<(schema::HttpSchema, schema::HttpSchema), Error> {
+    let source = deserialize_schema(src_content, src_format, src_loader)
+        .map_err(Error::InvalidSourceSchema)?;
 
     info!(
         src.schema.version = &source.version,
         src.schema.decoder = &source.schema_source
     );
 
-    let target: schema::HttpSchema = if let Ok(schema) =
-        serde_json::from_str::<OpenApi310>(tgt_content)
-    {
-        Ok(schema.into())
-    } else if let Ok(schema) = serde_json::from_str::<OpenApi303>(tgt_content)
-    {
-        Ok(schema.into())
-    } else if let Ok(schema) = serde_json::from_str::<SwaggerV2>(tgt_content) {
-        Ok(schema.into())
-    } else {
-        Err(Error::InvalidTargetSchema)
-    }?;
+    let target = deserialize_schema(tgt_content, tgt_format, tgt_loader)
+        .map_err(Error::InvalidTargetSchema)?;
 
     info!(
         tgt.schema.version = &target.version,
@@ -102,6 +168,267 @@ pub fn try_deserialize_schema(
     Ok((source, target))
 }
 
+#[derive(Deserialize)]
+struct SchemaVersion {
+    openapi: Option<Value>,
+    swagger: Option<Value>,
+}
+
+/// Document to decode. Bundled documents had their external refs inlined
+/// so they are decoded from a value, errors are located in the root text.
+enum Document<'c> {
+    Text(&'c str, SchemaFormat),
+    Bundled(Value, &'c str, SchemaFormat),
+}
+
+fn deserialize_schema(
+    content: &str,
+    format: SchemaFormat,
+    loader: Option<&dyn ReferenceLoader>,
+) -> Result<schema::HttpSchema, SchemaError> {
+    let mut document = Document::Text(content, format);
+
+    if let Some(loader) = loader {
+        let mut value: Value = match format {
+            SchemaFormat::Json => {
+                serde_json::from_str(content).map_err(malformed_json)?
+            }
+            SchemaFormat::Yaml => {
+                serde_yaml::from_str(content).map_err(malformed_yaml)?
+            }
+        };
+        if loader::bundle(&mut value, loader)? {
+            document = Document::Bundled(value, content, format);
+        }
+    }
+
+    let version: SchemaVersion = match &document {
+        Document::Text(content, SchemaFormat::Json) => {
+            serde_json::from_str(content).map_err(malformed_json)?
+        }
+        Document::Text(content, SchemaFormat::Yaml) => {
+            serde_yaml::from_str(content).map_err(malformed_yaml)?
+        }
+        Document::Bundled(value, ..) => {
+            SchemaVersion::deserialize(value).map_err(malformed_json)?
+        }
+    };
+
+    let display = |value: &Value| match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    };
+
+    match (version.openapi.as_ref(), version.swagger.as_ref()) {
+        (Some(openapi), _) => match display(openapi) {
+            v if v.starts_with("3.1") => {
+                decode::<OpenApi310>(&document, OpenApi310::id())
+                    .map(Into::into)
+            }
+            v if v.starts_with("3.0") => {
+                decode::<OpenApi303>(&document, OpenApi303::id())
+                    .map(Into::into)
+            }
+            v => Err(SchemaError::UnsupportedVersion(v)),
+        },
+        (None, Some(swagger)) => match display(swagger) {
+            v if v == "2.0" || v == "2" => {
+                decode::<SwaggerV2>(&document, SwaggerV2::id()).map(Into::into)
+            }
+            v => Err(SchemaError::UnsupportedVersion(v)),
+        },
+        (None, None) => Err(SchemaError::UnknownVersion),
+    }
+}
+
+fn decode<T: DeserializeOwned>(
+    document: &Document,
+    decoder: &'static str,
+) -> Result<T, SchemaError> {
+    match *document {
+        Document::Text(content, SchemaFormat::Json) => {
+            let mut de = serde_json::Deserializer::from_str(content);
+            let value =
+                serde_path_to_error::deserialize(&mut de).map_err(|err| {
+                    let path = err.path().to_string();
+                    let err = err.into_inner();
+                    let (line, column) = json_location(&err);
+                    invalid(decoder, path, &err.to_string(), line, column)
+                })?;
+            de.end().map_err(malformed_json)?;
+            Ok(value)
+        }
+        Document::Text(content, SchemaFormat::Yaml) => {
+            let de = serde_yaml::Deserializer::from_str(content);
+            serde_path_to_error::deserialize(de).map_err(|err| {
+                let path = err.path().to_string();
+                let err = err.into_inner();
+                let (line, column) = yaml_location(&err);
+                invalid(decoder, path, &err.to_string(), line, column)
+            })
+        }
+        Document::Bundled(ref value, ..) => {
+            serde_path_to_error::deserialize(value).map_err(|err| {
+                let path = err.path().to_string();
+                invalid(
+                    decoder,
+                    path,
+                    &err.into_inner().to_string(),
+                    None,
+                    None,
+                )
+            })
+        }
+    }
+    .map_err(|err| match *document {
+        Document::Text(content, format)
+        | Document::Bundled(_, content, format) => {
+            relocate(err, content, format)
+        }
+    })
+}
+
+/// Moves an `Invalid` error to the node at its path in the source text.
+/// Decoders report values buffered as a possible `$ref` at the position
+/// of the whole object, and bundled documents have no positions at all.
+fn relocate(
+    err: SchemaError,
+    content: &str,
+    format: SchemaFormat,
+) -> SchemaError {
+    let SchemaError::Invalid {
+        decoder,
+        path,
+        reason,
+        line,
+        column,
+    } = err
+    else {
+        return err;
+    };
+
+    let (line, column) = match locate(content, format, &path) {
+        Some((line, column)) => (Some(line), Some(column)),
+        None => (line, column),
+    };
+
+    SchemaError::Invalid {
+        decoder,
+        path,
+        reason,
+        line,
+        column,
+    }
+}
+
+/// Line and column of the deepest node on the path found in the text
+fn locate(
+    content: &str,
+    format: SchemaFormat,
+    path: &str,
+) -> Option<(usize, usize)> {
+    let seed = Locate(path.strip_prefix('.').unwrap_or(path));
+    match format {
+        SchemaFormat::Json => {
+            let mut de = serde_json::Deserializer::from_str(content);
+            match json_location(&seed.deserialize(&mut de).err()?) {
+                (Some(line), Some(column)) => Some((line, column)),
+                _ => None,
+            }
+        }
+        SchemaFormat::Yaml => {
+            let de = serde_yaml::Deserializer::from_str(content);
+            let location = seed.deserialize(de).err()?.location()?;
+            Some((location.line(), location.column()))
+        }
+    }
+}
+
+fn invalid(
+    decoder: &'static str,
+    path: String,
+    message: &str,
+    line: Option<usize>,
+    column: Option<usize>,
+) -> SchemaError {
+    let message = strip_location(message, line, column);
+    // serde_yaml prefixes messages with its own view of the path, which
+    // is the tracked path or one of its ancestors
+    let message = match message.split_once(": ") {
+        Some((prefix, rest))
+            if path.strip_prefix(prefix).is_some_and(|tail| {
+                tail.is_empty() || tail.starts_with(['.', '['])
+            }) =>
+        {
+            rest
+        }
+        _ => message,
+    };
+
+    let (nested, reason) = split_nested_error_path(message);
+    let path = std::iter::once(path.as_str())
+        .chain(nested)
+        .filter(|segment| *segment != ".")
+        .collect::<Vec<_>>()
+        .join(".");
+
+    SchemaError::Invalid {
+        decoder,
+        path: if path.is_empty() {
+            ".".to_owned()
+        } else {
+            path
+        },
+        reason: reason.to_owned(),
+        line,
+        column,
+    }
+}
+
+fn strip_location(
+    message: &str,
+    line: Option<usize>,
+    column: Option<usize>,
+) -> &str {
+    match (line, column) {
+        (Some(line), Some(column)) => message
+            .strip_suffix(&format!(" at line {line} column {column}"))
+            .unwrap_or(message),
+        _ => message,
+    }
+}
+
+fn json_location(err: &serde_json::Error) -> (Option<usize>, Option<usize>) {
+    if err.line() == 0 {
+        (None, None)
+    } else {
+        (Some(err.line()), Some(err.column()))
+    }
+}
+
+fn yaml_location(err: &serde_yaml::Error) -> (Option<usize>, Option<usize>) {
+    err.location()
+        .map_or((None, None), |l| (Some(l.line()), Some(l.column())))
+}
+
+fn malformed_json(err: serde_json::Error) -> SchemaError {
+    let (line, column) = json_location(&err);
+    SchemaError::Malformed {
+        reason: err.to_string(),
+        line,
+        column,
+    }
+}
+
+fn malformed_yaml(err: serde_yaml::Error) -> SchemaError {
+    let (line, column) = yaml_location(&err);
+    SchemaError::Malformed {
+        reason: err.to_string(),
+        line,
+        column,
+    }
+}
+
 pub fn get_schema_diff(
     src_schema: HttpSchema,
     tgt_schema: HttpSchema,
@@ -110,5 +437,197 @@ pub fn get_schema_diff(
     let tgt = Rc::new(tgt_schema);
 
     let context = HttpSchemaDiffContext::new(Rc::clone(&src), Rc::clone(&tgt));
-    src.diff(Some(&*tgt), &context)
+    let mut diff = src.diff(Some(&*tgt), &context);
+
+    if let Some(diff) = diff.get_mut() {
+        let source = src.diagnostics.iter().map(|diagnostic| {
+            diagnostic.clone().with_document(DiagnosticDocument::Source)
+        });
+        let target = tgt.diagnostics.iter().map(|diagnostic| {
+            diagnostic.clone().with_document(DiagnosticDocument::Target)
+        });
+        diff.diagnostics = source.chain(target).collect();
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_version() {
+        let id = OpenApi303::id();
+        assert!(is_current_diff_version(&format!("0.5.0-{id}-0.2.0")));
+        // Diffs stored before the model got 2020-12 keywords, webhooks,
+        // security, servers and nullable types
+        assert!(!is_current_diff_version(&format!("0.4.1-{id}-0.1.0")));
+    }
+
+    #[test]
+    fn test_detect_schema_format() {
+        assert_eq!(
+            SchemaFormat::detect("\n  {\"openapi\": 1}"),
+            SchemaFormat::Json
+        );
+        assert_eq!(SchemaFormat::detect("\u{feff}{}"), SchemaFormat::Json);
+        assert_eq!(SchemaFormat::detect("openapi: 3.0.3"), SchemaFormat::Yaml);
+        assert_eq!(
+            SchemaFormat::detect("---\nswagger: 2.0"),
+            SchemaFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn test_yaml_schema_matches_json() {
+        let (src, tgt) = try_deserialize_any_schema(
+            include_str!("../data/visitor-pointer-test.json"),
+            include_str!("../data/visitor-pointer-test.yaml"),
+        )
+        .unwrap();
+
+        assert_eq!(src.schema_source, OpenApi303::id());
+        assert_eq!(tgt.schema_source, OpenApi303::id());
+
+        let paths = tgt.paths.as_ref().unwrap();
+        let path = paths["/{entity_type}/{id}/change_tags"].value().unwrap();
+        let operation = path.post.as_ref().unwrap();
+        assert!(operation.responses.as_ref().unwrap().contains_key("200"));
+
+        let diff = get_schema_diff(src, tgt);
+        assert!(diff.is_same_or_none());
+    }
+
+    #[test]
+    fn test_yaml_schema_error_location() {
+        let content = include_str!("../data/visitor-pointer-test.yaml");
+        let broken = content.replacen("tags:\n      - Tags", "tags: {}", 1);
+
+        let err = try_deserialize_yaml_schema(&broken, content).unwrap_err();
+        let Error::InvalidSourceSchema(SchemaError::Invalid {
+            decoder,
+            path,
+            line,
+            column,
+            ..
+        }) = err
+        else {
+            panic!("Unexpected error: {err:?}");
+        };
+
+        assert_eq!(decoder, OpenApi303::id());
+        assert_eq!(path, "paths./{entity_type}/{id}/change_tags.post.tags");
+        assert_eq!((line, column), (Some(5), Some(13)));
+    }
+
+    #[test]
+    fn test_json_schema_error_path() {
+        let content = include_str!("../data/visitor-pointer-test.json");
+        let broken = content.replacen(
+            "\"required\": [",
+            "\"required\": 5, \"x\": [",
+            1,
+        );
+
+        let err = try_deserialize_schema(content, &broken).unwrap_err();
+        let Error::InvalidTargetSchema(SchemaError::Invalid {
+            decoder,
+            path,
+            reason,
+            ..
+        }) = err
+        else {
+            panic!("Unexpected error: {err:?}");
+        };
+
+        assert_eq!(decoder, OpenApi303::id());
+        assert_eq!(
+            path,
+            "paths./{entity_type}/{id}/change_tags.post.requestBody.content.\
+             application/json.schema.properties.add.items.required"
+        );
+        assert_eq!(reason, "invalid type: integer `5`, expected a sequence");
+    }
+
+    #[test]
+    fn test_schema_version_errors() {
+        let content = include_str!("../data/visitor-pointer-test.json");
+
+        let err = try_deserialize_schema("{\"info\": {}}", content);
+        assert!(matches!(
+            err,
+            Err(Error::InvalidSourceSchema(SchemaError::UnknownVersion))
+        ));
+
+        let err = try_deserialize_schema("{\"swagger\": \"1.2\"}", content);
+        assert!(matches!(
+            err,
+            Err(Error::InvalidSourceSchema(SchemaError::UnsupportedVersion(v)))
+                if v == "1.2"
+        ));
+
+        let err = try_deserialize_any_schema("swagger: 2.0\n: :", content);
+        assert!(matches!(
+            err,
+            Err(Error::InvalidSourceSchema(SchemaError::Malformed { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_schema_external_refs() {
+        let content = include_str!("../data/refs/openapi.yaml");
+        let loader = loader::FsReferenceLoader::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/refs"
+        ));
+
+        let (src, _) = try_deserialize_schema_with_loaders(
+            content, &loader, content, &loader,
+        )
+        .unwrap();
+
+        let components = src.components.as_ref().unwrap();
+        let schemas = components.schemas.as_ref().unwrap();
+        let user = schemas["User"].value().unwrap();
+        let properties = user.properties.as_ref().unwrap();
+        assert!(properties["address"].value().is_some());
+
+        let paths = src.paths.as_ref().unwrap();
+        let path = paths["/users/{id}"].value().unwrap();
+        let operation = path.get.as_ref().unwrap();
+        let parameters = operation.parameters.as_ref().unwrap();
+        assert_eq!(parameters[0].value().unwrap().name, "id");
+
+        // Bundled documents are located in the root document text
+        let broken =
+            content.replacen("description: User", "description: {}", 1);
+        let err = try_deserialize_schema_with_loaders(
+            &broken, &loader, content, &loader,
+        )
+        .unwrap_err();
+        let Error::InvalidSourceSchema(SchemaError::Invalid {
+            path,
+            line,
+            column,
+            ..
+        }) = err
+        else {
+            panic!("Unexpected error: {err:?}");
+        };
+        assert_eq!(path, "paths./users/{id}.get.responses.200.description");
+        assert_eq!((line, column), (Some(12), Some(24)));
+
+        let err = try_deserialize_schema_with_loaders(
+            content,
+            &loader::MemoryReferenceLoader::new(),
+            content,
+            &loader,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidSourceSchema(SchemaError::Reference(_))
+        ));
+    }
 }
@@ -0,0 +1,567 @@
+use std::collections::HashMap;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::diagnostic::{is_data_keyword, is_named_map};
+use crate::error::ReferenceError;
+use crate::SchemaFormat;
+
+/// Source of documents referenced by external `$ref`s
+/// e.g. `./schemas/user.yaml#/User`.
+///
+/// Document ids passed to the loader are already resolved against the
+/// referring document, so `./user.yaml` referenced from `schemas/api.yaml`
+/// is loaded as `schemas/user.yaml`.
+pub trait ReferenceLoader {
+    fn load(&self, document: &str) -> Result<String, IoError>;
+}
+
+/// Loads referenced documents relative to a local directory. Documents
+/// outside of the directory are refused.
+#[derive(Debug, Clone)]
+pub struct FsReferenceLoader {
+    root: PathBuf,
+}
+
+impl FsReferenceLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl ReferenceLoader for FsReferenceLoader {
+    fn load(&self, document: &str) -> Result<String, IoError> {
+        let root = self.root.canonicalize()?;
+        let path = root.join(document).canonicalize()?;
+        if !path.starts_with(&root) {
+            return Err(IoError::new(
+                ErrorKind::PermissionDenied,
+                "document is outside of the root directory",
+            ));
+        }
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Loads referenced documents from an in-memory map keyed by document id
+#[derive(Debug, Clone, Default)]
+pub struct MemoryReferenceLoader {
+    documents: HashMap<String, String>,
+}
+
+impl MemoryReferenceLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(
+        &mut self,
+        document: impl Into<String>,
+        content: impl Into<String>,
+    ) -> &mut Self {
+        self.documents.insert(document.into(), content.into());
+        self
+    }
+}
+
+impl From<HashMap<String, String>> for MemoryReferenceLoader {
+    fn from(documents: HashMap<String, String>) -> Self {
+        Self { documents }
+    }
+}
+
+impl ReferenceLoader for MemoryReferenceLoader {
+    fn load(&self, document: &str) -> Result<String, IoError> {
+        self.documents.get(document).cloned().ok_or_else(|| {
+            IoError::new(ErrorKind::NotFound, "document not found")
+        })
+    }
+}
+
+/// Replaces every external `$ref` of the document with the value it points
+/// to, so only refs local to the root document are left.
+///
+/// Recursive values, e.g. a tree node schema referencing itself, are
+/// inlined once and referenced locally from inside. Values inlined outside
+/// of components are moved into the document schemas for that.
+/// Returns whether the document was changed.
+pub fn bundle(
+    document: &mut Value,
+    loader: &dyn ReferenceLoader,
+) -> Result<bool, ReferenceError> {
+    let schemas = if document.get("swagger").is_some() {
+        "/definitions"
+    } else {
+        "/components/schemas"
+    };
+
+    let mut bundler = Bundler {
+        loader,
+        documents: HashMap::new(),
+        stack: Vec::new(),
+        schemas,
+        names: document
+            .pointer(schemas)
+            .and_then(Value::as_object)
+            .map(|schemas| schemas.keys().cloned().collect())
+            .unwrap_or_default(),
+        hoisted: HashMap::new(),
+        moved: Vec::new(),
+        changed: false,
+    };
+    bundler.resolve(document, "", "", false, true)?;
+
+    if !bundler.moved.is_empty() {
+        let mut target = &mut *document;
+        for segment in schemas.split('/').skip(1) {
+            if !target.is_object() {
+                *target = Value::Object(Default::default());
+            }
+            target = target
+                .as_object_mut()
+                .expect("target is an object")
+                .entry(segment)
+                .or_insert_with(|| Value::Object(Default::default()));
+        }
+        if let Some(target) = target.as_object_mut() {
+            target.extend(bundler.moved);
+        }
+    }
+
+    Ok(bundler.changed)
+}
+
+/// Reference being resolved with the root document location it is inlined
+/// at, and whether it is the whole value of the previous reference
+struct Resolving {
+    reference: String,
+    location: String,
+    chained: bool,
+}
+
+struct Bundler<'l> {
+    loader: &'l dyn ReferenceLoader,
+    documents: HashMap<String, Value>,
+    /// References being resolved at the moment, used to detect loops
+    stack: Vec<Resolving>,
+    /// Location of the root document schemas
+    schemas: &'static str,
+    /// Schema names taken in the root document
+    names: Vec<String>,
+    /// Names of recursive references moved into the root document schemas
+    hoisted: HashMap<String, String>,
+    moved: Vec<(String, Value)>,
+    changed: bool,
+}
+
+impl<'l> Bundler<'l> {
+    /// Resolves refs of the `node` which belongs to the `base` document
+    /// and is inlined at `location` of the root document. An empty `base`
+    /// stands for the root document. The `node` is a map of user-given
+    /// names when `named` is set, refs inside user values are kept as is.
+    fn resolve(
+        &mut self,
+        node: &mut Value,
+        base: &str,
+        location: &str,
+        named: bool,
+        chained: bool,
+    ) -> Result<(), ReferenceError> {
+        match node {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    let reference = reference.clone();
+                    let (document, pointer) = reference
+                        .split_once('#')
+                        .unwrap_or((reference.as_str(), ""));
+
+                    if document.is_empty() && base.is_empty() {
+                        return Ok(());
+                    }
+
+                    let document = if document.is_empty() {
+                        base.to_owned()
+                    } else {
+                        join(base, document)
+                    };
+
+                    *node = self.resolve_reference(
+                        &document, pointer, location, chained,
+                    )?;
+                    self.changed = true;
+                    return Ok(());
+                }
+
+                for (key, value) in map.iter_mut() {
+                    let is_keyword = !named;
+                    if is_keyword && is_data_keyword(key, value) {
+                        continue;
+                    }
+                    let is_named = is_keyword && is_named_map(key);
+                    let location = format!("{location}/{}", escape(key));
+                    self.resolve(value, base, &location, is_named, false)?;
+                }
+            }
+            Value::Array(values) => {
+                for (idx, value) in values.iter_mut().enumerate() {
+                    let location = format!("{location}/{idx}");
+                    self.resolve(value, base, &location, false, false)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn resolve_reference(
+        &mut self,
+        document: &str,
+        pointer: &str,
+        location: &str,
+        chained: bool,
+    ) -> Result<Value, ReferenceError> {
+        let reference = format!("{document}#{pointer}");
+
+        let is_resolving = self.stack.iter().any(|v| v.reference == reference);
+        if let (Some(name), false) =
+            (self.hoisted.get(&reference), is_resolving)
+        {
+            return Ok(local_ref(&format!(
+                "{}/{}",
+                self.schemas,
+                escape(name)
+            )));
+        }
+
+        if let Some(idx) =
+            self.stack.iter().position(|v| v.reference == reference)
+        {
+            return self.resolve_loop(idx, reference, chained);
+        }
+
+        let mut value = self
+            .load(document)?
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| ReferenceError::NotFound(reference.clone()))?;
+
+        self.stack.push(Resolving {
+            reference,
+            location: location.to_owned(),
+            chained,
+        });
+        self.resolve(&mut value, document, location, false, true)?;
+        let resolving = self.stack.pop().expect("reference is resolving");
+
+        // Recursive value inlined outside of schemas was referenced from
+        // inside, so it is moved into the schemas
+        match self.hoisted.get(&resolving.reference) {
+            Some(name) => {
+                self.moved.push((name.clone(), value));
+                Ok(local_ref(&format!("{}/{}", self.schemas, escape(name))))
+            }
+            None => Ok(value),
+        }
+    }
+
+    /// Local ref to the value being resolved since `idx`. Loops made of
+    /// refs only have no value and are rejected.
+    fn resolve_loop(
+        &mut self,
+        idx: usize,
+        reference: String,
+        chained: bool,
+    ) -> Result<Value, ReferenceError> {
+        if chained && self.stack[idx + 1..].iter().all(|v| v.chained) {
+            let mut references: Vec<_> =
+                self.stack.iter().map(|v| v.reference.clone()).collect();
+            references.push(reference);
+            return Err(ReferenceError::Loop(references));
+        }
+
+        let location = &self.stack[idx].location;
+        if location.starts_with("/components/")
+            || location.starts_with("/definitions/")
+        {
+            return Ok(local_ref(location));
+        }
+
+        if !self.hoisted.contains_key(&reference) {
+            let name = self.schema_name(&reference);
+            self.hoisted.insert(reference.clone(), name);
+        }
+        let name = &self.hoisted[&reference];
+        Ok(local_ref(&format!("{}/{}", self.schemas, escape(name))))
+    }
+
+    /// Free schema name, taken from the last pointer segment or document
+    fn schema_name(&mut self, reference: &str) -> String {
+        let (document, pointer) =
+            reference.split_once('#').unwrap_or((reference, ""));
+        let base = match pointer.rsplit_once('/') {
+            Some((_, segment)) if !segment.is_empty() => {
+                segment.replace("~1", "/").replace("~0", "~")
+            }
+            _ => {
+                let file = document.rsplit('/').next().unwrap_or(document);
+                file.split('.').next().unwrap_or(file).to_owned()
+            }
+        };
+
+        let mut name = base.clone();
+        let mut counter = 1;
+        while self.names.contains(&name) {
+            counter += 1;
+            name = format!("{base}{counter}");
+        }
+        self.names.push(name.clone());
+        name
+    }
+
+    fn load(&mut self, document: &str) -> Result<&Value, ReferenceError> {
+        if !self.documents.contains_key(document) {
+            let content = self.loader.load(document).map_err(|err| {
+                ReferenceError::Load {
+                    document: document.to_owned(),
+                    reason: err.to_string(),
+                }
+            })?;
+
+            let parse_error = |reason: String| ReferenceError::Parse {
+                document: document.to_owned(),
+                reason,
+            };
+            let value = match SchemaFormat::detect(&content) {
+                SchemaFormat::Json => serde_json::from_str(&content)
+                    .map_err(|err| parse_error(err.to_string()))?,
+                SchemaFormat::Yaml => serde_yaml::from_str(&content)
+                    .map_err(|err| parse_error(err.to_string()))?,
+            };
+
+            self.documents.insert(document.to_owned(), value);
+        }
+
+        Ok(&self.documents[document])
+    }
+}
+
+/// Escapes a key as a JSON pointer segment
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn local_ref(location: &str) -> Value {
+    let mut map = serde_json::Map::new();
+    map.insert("$ref".to_owned(), Value::String(format!("#{location}")));
+    Value::Object(map)
+}
+
+/// Resolves `reference` document path against the `base` document id
+fn join(base: &str, reference: &str) -> String {
+    if reference.contains("://") || reference.starts_with('/') {
+        return reference.to_owned();
+    }
+
+    let directory = base.rsplit_once('/').map_or("", |(dir, _)| dir);
+    if directory.contains("://") {
+        return format!("{directory}/{reference}");
+    }
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in directory.split('/').chain(reference.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|s| *s != "..") => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_join() {
+        assert_eq!(join("", "./user.yaml"), "user.yaml");
+        assert_eq!(join("schemas/api.yaml", "user.yaml"), "schemas/user.yaml");
+        assert_eq!(join("schemas/api.yaml", "../common.yaml"), "common.yaml");
+        assert_eq!(join("api.yaml", "../common.yaml"), "../common.yaml");
+        assert_eq!(
+            join("https://example.com/api.yaml", "user.yaml"),
+            "https://example.com/user.yaml"
+        );
+    }
+
+    #[test]
+    fn test_bundle_external_refs() {
+        let mut loader = MemoryReferenceLoader::new();
+        loader
+            .insert(
+                "schemas/user.yaml",
+                "User:\n  type: object\n  properties:\n    \
+                 address:\n      $ref: './address.json#/Address'\n    \
+                 group:\n      $ref: '#/Group'\nGroup:\n  type: string\n",
+            )
+            .insert(
+                "schemas/address.json",
+                r#"{"Address": {"type": "object"}}"#,
+            );
+
+        let mut document = json!({
+            "openapi": "3.0.3",
+            "components": {"schemas": {
+                "User": {"$ref": "./schemas/user.yaml#/User"},
+                "Local": {"$ref": "#/components/schemas/User"},
+            }},
+        });
+
+        assert!(bundle(&mut document, &loader).unwrap());
+        assert_eq!(
+            document["components"]["schemas"],
+            json!({
+                "User": {
+                    "type": "object",
+                    "properties": {
+                        "address": {"type": "object"},
+                        "group": {"type": "string"},
+                    },
+                },
+                "Local": {"$ref": "#/components/schemas/User"},
+            })
+        );
+    }
+
+    #[test]
+    fn test_bundle_keeps_data_values() {
+        let mut loader = MemoryReferenceLoader::new();
+        loader.insert("text.json", r#"{"Text": {"type": "string"}}"#);
+
+        // Values shaped like refs are neither loaded nor inlined, while a
+        // property named like a data keyword is still resolved
+        let mut document = json!({
+            "openapi": "3.0.3",
+            "components": {"schemas": {"Link": {
+                "type": "object",
+                "properties": {
+                    "default": {"$ref": "text.json#/Text"},
+                },
+                "example": {"$ref": "./other.yaml#/Link"},
+                "default": {"$ref": "./other.yaml#/Link"},
+                "examples": [{"$ref": "./other.yaml#/Link"}],
+                "x-origin": {"$ref": "./other.yaml#/Link"},
+            }}},
+        });
+        let mut expected = document.clone();
+        expected["components"]["schemas"]["Link"]["properties"]["default"] =
+            json!({"type": "string"});
+
+        assert!(bundle(&mut document, &loader).unwrap());
+        assert_eq!(document, expected);
+    }
+
+    #[test]
+    fn test_bundle_errors() {
+        let mut loader = MemoryReferenceLoader::new();
+        loader
+            .insert("a.json", r##"{"A": {"$ref": "b.json#/B"}}"##)
+            .insert("b.json", r##"{"B": {"$ref": "a.json#/A"}}"##);
+
+        let mut document = json!({"schema": {"$ref": "a.json#/A"}});
+        let err = bundle(&mut document, &loader).unwrap_err();
+        assert!(matches!(err, ReferenceError::Loop(refs) if refs.len() == 3));
+
+        let mut document = json!({"schema": {"$ref": "a.json#/C"}});
+        let err = bundle(&mut document, &loader).unwrap_err();
+        assert!(
+            matches!(err, ReferenceError::NotFound(r) if r == "a.json#/C")
+        );
+
+        let mut document = json!({"schema": {"$ref": "c.json"}});
+        let err = bundle(&mut document, &loader).unwrap_err();
+        assert!(matches!(err, ReferenceError::Load { .. }));
+
+        let mut document = json!({"schema": {"$ref": "#/local"}});
+        assert!(!bundle(&mut document, &loader).unwrap());
+    }
+
+    #[test]
+    fn test_bundle_recursive_refs() {
+        let mut loader = MemoryReferenceLoader::new();
+        loader.insert(
+            "node.yaml",
+            "Node:\n  type: object\n  properties:\n    children:\n      \
+             type: array\n      items:\n        $ref: '#/Node'\n",
+        );
+
+        let node = |reference: &str| {
+            json!({
+                "type": "object",
+                "properties": {"children": {
+                    "type": "array",
+                    "items": {"$ref": reference},
+                }},
+            })
+        };
+
+        // Inlined into components, referenced from inside
+        let mut document = json!({
+            "openapi": "3.0.3",
+            "components": {"schemas": {
+                "Tree": {"$ref": "node.yaml#/Node"},
+            }},
+        });
+        assert!(bundle(&mut document, &loader).unwrap());
+        assert_eq!(
+            document["components"]["schemas"]["Tree"],
+            node("#/components/schemas/Tree")
+        );
+
+        // Moved into components when referenced from elsewhere
+        let mut document = json!({
+            "openapi": "3.0.3",
+            "paths": {"/tree": {"get": {"parameters": [
+                {"schema": {"$ref": "node.yaml#/Node"}},
+                {"schema": {"$ref": "node.yaml#/Node"}},
+            ]}}},
+            "components": {"schemas": {"Node": {"type": "string"}}},
+        });
+        assert!(bundle(&mut document, &loader).unwrap());
+        let parameters = &document["paths"]["/tree"]["get"]["parameters"];
+        assert_eq!(
+            parameters,
+            &json!([
+                {"schema": {"$ref": "#/components/schemas/Node2"}},
+                {"schema": {"$ref": "#/components/schemas/Node2"}},
+            ])
+        );
+        assert_eq!(
+            document["components"]["schemas"]["Node2"],
+            node("#/components/schemas/Node2")
+        );
+    }
+
+    #[test]
+    fn test_fs_loader_root() {
+        let loader = FsReferenceLoader::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/refs"
+        ));
+
+        assert!(loader.load("./schemas/../parameters.yaml").is_ok());
+
+        let err = loader.load("../visitor-pointer-test.json").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+
+        let err = loader
+            .load(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::diff_result_type::DiffResultType;
 
 #[derive(Debug, Clone)]
@@ -32,12 +34,17 @@ impl PointerAncestor {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum PathPointerScope {
     Paths,
     Path,
     Operation,
 
+    Webhooks,
+    Callbacks,
+    Callback,
+
     RequestBody,
     Responses,
     ResponseCode,
@@ -56,16 +63,58 @@ pub enum PathPointerScope {
     SchemaAnyOf,
     SchemaOneOf,
     SchemaAdditionalProperties,
+    SchemaPatternProperties,
+    SchemaPropertyNames,
+    SchemaUnevaluatedProperties,
+
+    SchemaPrefixItems,
+    SchemaContains,
+
+    SchemaIf,
+    SchemaThen,
+    SchemaElse,
+    SchemaDependentSchemas,
+    SchemaDefs,
+
+    SecuritySchemes,
+    SecurityScheme,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Side of the contract which sends the data found at a pointer,
+/// from the point of view of the API consumer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerDirection {
+    /// Sent by the consumer, e.g. request bodies and parameters of paths
+    Request,
+    /// Sent to the consumer, e.g. responses of paths or webhook requests
+    Response,
+}
+
+impl PointerDirection {
+    pub fn flip(self) -> Self {
+        match self {
+            PointerDirection::Request => PointerDirection::Response,
+            PointerDirection::Response => PointerDirection::Request,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PathPointerComponent {
     pub kind: DiffResultType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scope: Option<PathPointerScope>,
+    /// Stable name used in the JSON pointer instead of the `path` segment,
+    /// e.g. `query:limit` for a parameter matched by position
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Serialized as the plain list of its components
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct PathPointer {
     pub components: Vec<PathPointerComponent>,
 }
@@ -81,6 +130,7 @@ impl PathPointer {
                 scope,
                 kind: context.into(),
                 path: path.map(|path| path.into()),
+                name: None,
             }],
         }
     }
@@ -109,23 +159,71 @@ impl PathPointer {
             scope,
             kind: context.into(),
             path: path.map(|path| path.into()),
+            name: None,
         });
         new
     }
 
+    /// Names the last component for the JSON pointer
+    pub fn with_name<S: Into<String>>(mut self, name: Option<S>) -> Self {
+        if let Some(component) = self.components.last_mut() {
+            component.name = name.map(|name| name.into());
+        }
+        self
+    }
+
     pub fn get(
         &self,
         scope: PathPointerScope,
     ) -> Option<&PathPointerComponent> {
         self.components
             .iter()
-            .find(|c| c.scope.as_ref().map_or(false, |v| v == &scope))
+            .find(|c| c.scope.as_ref() == Some(&scope))
     }
 
     pub fn is_in(&self, scope: PathPointerScope) -> bool {
         self.get(scope).is_some()
     }
 
+    /// Direction of the data at the pointer, flipped within webhooks and
+    /// callbacks where the API calls the consumer
+    pub fn direction(&self) -> Option<PointerDirection> {
+        let mut direction = None;
+        let mut flipped = false;
+
+        for component in &self.components {
+            match component.scope {
+                Some(PathPointerScope::Webhooks)
+                | Some(PathPointerScope::Callbacks) => {
+                    flipped = !flipped;
+                    direction = None;
+                }
+                Some(PathPointerScope::RequestBody)
+                | Some(PathPointerScope::Parameters) => {
+                    direction = Some(PointerDirection::Request);
+                }
+                Some(PathPointerScope::Responses) => {
+                    direction = Some(PointerDirection::Response);
+                }
+                _ => {}
+            }
+        }
+
+        if flipped {
+            direction.map(PointerDirection::flip)
+        } else {
+            direction
+        }
+    }
+
+    pub fn is_request(&self) -> bool {
+        self.direction() == Some(PointerDirection::Request)
+    }
+
+    pub fn is_response(&self) -> bool {
+        self.direction() == Some(PointerDirection::Response)
+    }
+
     pub fn this(&self) -> DiffResultType {
         self.get_primary(None)
     }
@@ -233,6 +331,25 @@ impl PathPointer {
             .join("/")
     }
 
+    /// Path as an RFC 6901 JSON pointer, with `~` and `/` inside segments
+    /// escaped, e.g. `/paths/~1pets~1{id}/get`. Parameters are named
+    /// `{in}:{name}` rather than by position, e.g.
+    /// `/paths/~1pets/get/parameters/query:limit/schema`
+    pub fn get_json_pointer(&self) -> String {
+        self.components
+            .iter()
+            .filter_map(|c| c.name.as_deref().or(c.path.as_deref()))
+            .map(|segment| {
+                format!("/{}", segment.replace('~', "~0").replace('/', "~1"))
+            })
+            .collect()
+    }
+
+    /// Last path segment, e.g. the key of a map entry
+    pub fn segment(&self) -> Option<&str> {
+        self.components.iter().rev().find_map(|c| c.path.as_deref())
+    }
+
     pub fn startswith(&self, value: &PathPointer) -> bool {
         self.get_path().starts_with(&value.get_path())
     }
@@ -5,6 +5,7 @@ use indexmap::IndexMap;
 use serde_json::Value;
 
 use crate::core::{Either, Keyed, MayBeRefCore, ReferenceDescriptor};
+use crate::diagnostic::Diagnostic;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpSchemaRef {
@@ -32,16 +33,34 @@ pub struct HttpSchema {
     pub info: Option<Info>,
     pub servers: Option<Vec<Server>>,
     pub paths: Option<IndexMap<String, MayBeRef<Path>>>,
+    pub webhooks: Option<IndexMap<String, MayBeRef<Path>>>,
     pub components: Option<Components>,
-    // TODO:
-    // pub security:
+    pub security: Option<Vec<SecurityRequirement>>,
     pub tags: Option<Vec<Tag>>,
     pub external_docs: Option<ExternalDoc>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 impl HttpSchema {
     pub fn schema_version() -> &'static str {
-        "0.4.1"
+        "0.5.0"
+    }
+}
+
+/// Security scheme names mapped to the scopes required from them
+pub type SecurityRequirement = IndexMap<String, Vec<String>>;
+
+/// Resolves the requirements applied to an operation, returning them along
+/// with whether they were inherited from the document level
+pub fn effective_security(
+    operation: Option<Vec<SecurityRequirement>>,
+    document: &Option<Vec<SecurityRequirement>>,
+) -> (Option<Vec<SecurityRequirement>>, Option<bool>) {
+    match operation {
+        Some(security) => (Some(security), Some(false)),
+        None => (document.clone(), document.as_ref().map(|_| true)),
     }
 }
 
@@ -285,24 +304,22 @@ pub struct Schema {
     // #[serde(rename = "$dynamicRef")]
     // dynamic_ref_: Option<String>,
     //
-    // #[serde(rename = "$def")]
-    // defs_: Option<IndexMap<String, Schema>>,
-    //
+    #[serde(rename = "$defs")]
+    pub defs: Option<IndexMap<String, MayBeRef<Schema>>>,
+
     // #[serde(rename = "$comment")]
     // comment_: Option<IndexMap<String, Schema>>,
+    pub r#if: Box<Option<MayBeRef<Schema>>>,
+    pub then: Box<Option<MayBeRef<Schema>>>,
+    pub r#else: Box<Option<MayBeRef<Schema>>>,
+    pub dependent_schemas: Option<IndexMap<String, MayBeRef<Schema>>>,
 
-    // if
-    // then
-    // else
-    // dependentSchemas
-
-    // contains
     pub title: Option<String>,
     pub multiple_of: Option<f32>,
     pub maximum: Option<f32>,
-    pub exclusive_maximum: Option<bool>,
+    pub exclusive_maximum: Option<Either<bool, f32>>,
     pub minimum: Option<f32>,
-    pub exclusive_minimum: Option<bool>,
+    pub exclusive_minimum: Option<Either<bool, f32>>,
     pub max_length: Option<usize>,
     pub min_length: Option<usize>,
     pub pattern: Option<String>,
@@ -314,13 +331,13 @@ pub struct Schema {
     pub required: Option<Vec<String>>,
     pub r#enum: Option<Vec<Value>>,
 
-    // r#const: Option<Vec>,
+    pub r#const: Option<Value>,
 
-    // pub contains: Box<Option<MayBeRef<Schema>>>
-    // pub min_contains: Option<usize>,
-    // pub max_contains: Option<bool>,
+    pub contains: Box<Option<MayBeRef<Schema>>>,
+    pub min_contains: Option<usize>,
+    pub max_contains: Option<usize>,
 
-    // dependentRequired
+    pub dependent_required: Option<IndexMap<String, Vec<String>>>,
 
     // https://json-schema.org/draft/2020-12/json-schema-validation.html#:~:text=to%20these%20keywords.-,8.3.,-contentEncoding
     // content_encoding
@@ -334,16 +351,17 @@ pub struct Schema {
 
     pub items: Box<Option<MayBeRef<Schema>>>,
 
-    // prefix_items: Option<Vec<MayBeRef<Schema>>>,
+    pub prefix_items: Option<Vec<MayBeRef<Schema>>>,
 
     // unevaluated_items: Box<Option<MayBeRef<Schema>>>
     pub properties: Option<IndexMap<String, MayBeRef<Schema>>>,
     pub additional_properties: Option<Either<bool, MayBeRef<Schema>>>, // TODO: Can be Bool in 3.1??
-    // pattern_properties: Option<IndexMap<String, MayBeRef<Schema>>>,
+    pub pattern_properties: Option<IndexMap<String, MayBeRef<Schema>>>,
 
-    // property_names: Box<Option<MayBeRef<Schema>>>,
+    pub property_names: Box<Option<MayBeRef<Schema>>>,
+
+    pub unevaluated_properties: Option<Either<bool, MayBeRef<Schema>>>,
 
-    // unevaluated_properties: Box<Option<MayBeRef<Schema>>>
     pub description: Option<String>,
     pub format: Option<String>,
     pub default: Option<Value>,
@@ -355,7 +373,7 @@ pub struct Schema {
     pub external_docs: Option<ExternalDoc>,
     pub example: Option<Value>,
 
-    // examples: Option<Vec<Value>>,
+    pub examples: Option<Vec<Value>>,
     pub deprecated: Option<bool>,
 
     #[serde(flatten)]
@@ -376,7 +394,33 @@ impl Keyed<usize> for Schema {
             // return kind.to_string();
         }
 
-        idx.to_string()
+        // Untitled schemas are matched by their content
+        serde_json::to_string(self).unwrap_or_else(|_| idx.to_string())
+    }
+
+    fn is_anonymous(&self) -> bool {
+        self.r#type.is_none() || self.title.is_none()
+    }
+
+    /// Schemas of different types are never close, otherwise the number of
+    /// equal keywords counts
+    fn similarity(&self, other: &Self) -> usize {
+        let (Ok(Value::Object(this)), Ok(Value::Object(other))) =
+            (serde_json::to_value(self), serde_json::to_value(other))
+        else {
+            return 0;
+        };
+
+        if this.get("type") != other.get("type") {
+            return 0;
+        }
+
+        1 + this
+            .iter()
+            .filter(|(key, value)| {
+                *key != "type" && other.get(*key) == Some(*value)
+            })
+            .count()
     }
 }
 
@@ -421,13 +465,20 @@ pub struct Operation {
     pub servers: Option<Vec<Server>>,
     pub parameters: Option<Vec<MayBeRef<Parameter>>>,
 
-    pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
+    /// Effective requirements, taken from the document when not overridden
+    pub security: Option<Vec<SecurityRequirement>>,
+    pub security_inherited: Option<bool>,
 
-    // TODO:
-    // pub callbacks
+    pub callbacks: Option<IndexMap<String, Callback>>,
     pub deprecated: Option<bool>,
+
+    #[serde(flatten)]
+    pub custom_fields: IndexMap<String, Value>,
 }
 
+/// Path items keyed by the runtime expression of the callback url
+pub type Callback = IndexMap<String, MayBeRef<Path>>;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Path {
     pub get: Option<Operation>,
@@ -562,6 +613,6 @@ mod tests {
       }
         "#;
         let op: Schema = serde_json::from_str(sc_def).unwrap();
-        assert!(matches!(op.discriminator, Some(_)))
+        assert!(op.discriminator.is_some())
     }
 }
@@ -9,6 +9,7 @@ use crate::core::{
     MayBeRefCoreDiff, Referencable, VecDiff,
 };
 
+use crate::diagnostic::Diagnostic;
 use crate::schema::HttpSchemaRef;
 use crate::schema_diff_utils::{PathsMapPathResolver, TypeVecDiffSorter};
 
@@ -38,9 +39,16 @@ pub struct HttpSchemaDiff {
     pub servers: DiffResult<VecDiff<ServerDiff>>,
     pub paths:
         DiffResult<MapDiff<MayBeRefDiff<PathDiff>, PathsMapPathResolver>>,
+    #[serde(default)]
+    pub webhooks: DiffResult<MapDiff<MayBeRefDiff<PathDiff>>>,
     pub components: DiffResult<ComponentsDiff>,
+    #[serde(default)]
+    pub security: DiffResult<VecDiff<MapDiff<VecDiff<String>>>>,
     pub tags: DiffResult<VecDiff<TagDiff>>,
     pub external_docs: DiffResult<ExternalDocDiff>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 impl HttpSchemaDiff {
@@ -401,11 +409,11 @@ pub struct SchemaDiff {
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub maximum: DiffResult<f32>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
-    pub exclusive_maximum: DiffResult<bool>,
+    pub exclusive_maximum: DiffResult<EitherDiff<bool, f32>>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub minimum: DiffResult<f32>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
-    pub exclusive_minimum: DiffResult<bool>,
+    pub exclusive_minimum: DiffResult<EitherDiff<bool, f32>>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub max_length: DiffResult<usize>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
@@ -428,6 +436,17 @@ pub struct SchemaDiff {
     pub required: DiffResult<VecDiff<String>>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub r#enum: DiffResult<VecDiff<Value>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub r#const: DiffResult<Value>,
+
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub contains: Box<DiffResult<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub min_contains: DiffResult<usize>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub max_contains: DiffResult<usize>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub dependent_required: DiffResult<MapDiff<VecDiff<String>>>,
 
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub r#type:
@@ -443,11 +462,32 @@ pub struct SchemaDiff {
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub items: Box<DiffResult<MayBeRefDiff<SchemaDiff>>>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub prefix_items: DiffResult<VecDiff<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub properties: DiffResult<MapDiff<MayBeRefDiff<SchemaDiff>>>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub additional_properties:
         DiffResult<EitherDiff<bool, MayBeRefDiff<SchemaDiff>>>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub pattern_properties: DiffResult<MapDiff<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub property_names: Box<DiffResult<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub unevaluated_properties:
+        DiffResult<EitherDiff<bool, MayBeRefDiff<SchemaDiff>>>,
+
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub r#if: Box<DiffResult<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub then: Box<DiffResult<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub r#else: Box<DiffResult<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub dependent_schemas: DiffResult<MapDiff<MayBeRefDiff<SchemaDiff>>>,
+
+    #[serde(rename = "$defs", skip_serializing_if = "DiffResult::is_none")]
+    pub defs: DiffResult<MapDiff<MayBeRefDiff<SchemaDiff>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub description: DiffResult<String>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub format: DiffResult<String>,
@@ -467,6 +507,8 @@ pub struct SchemaDiff {
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub example: DiffResult<Value>,
     #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub examples: DiffResult<VecDiff<Value>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub deprecated: DiffResult<bool>,
 
     #[serde(skip_serializing_if = "check_custom_fields")]
@@ -538,11 +580,21 @@ pub struct OperationDiff {
 
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub security: DiffResult<VecDiff<MapDiff<VecDiff<String>>>>,
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub security_inherited: DiffResult<bool>,
+
+    #[serde(skip_serializing_if = "DiffResult::is_none")]
+    pub callbacks: DiffResult<MapDiff<CallbackDiff>>,
 
     #[serde(skip_serializing_if = "DiffResult::is_none")]
     pub deprecated: DiffResult<bool>,
+
+    #[serde(skip_serializing_if = "check_custom_fields")]
+    pub custom_fields: DiffResult<MapDiff<Value>>,
 }
 
+pub type CallbackDiff = MapDiff<MayBeRefDiff<PathDiff>>;
+
 #[derive(
     Debug, Clone, Serialize, Deserialize, Empty, Diff, DiffOwnChanges,
 )]
@@ -575,126 +627,78 @@ pub struct PathDiff {
 
 impl Referencable for PathDiff {}
 
+/// Follows component refs until a value is found. Returns `None` for
+/// dangling and cyclic refs.
+fn deref_component_diff<'a, T: Referencable>(
+    components: Option<&'a MapDiff<MayBeRefDiff<T>>>,
+    prefix: &str,
+    may_be_ref: &'a MayBeRefDiff<T>,
+) -> Option<&'a DiffResult<T>> {
+    let mut visited = Vec::new();
+    let mut may_be_ref = may_be_ref;
+    loop {
+        match may_be_ref {
+            MayBeRefDiff::Value(value) => return Some(value),
+            MayBeRefDiff::Ref(value) => {
+                let reference = value.reference.as_str();
+                if visited.contains(&reference) {
+                    return None;
+                }
+                visited.push(reference);
+
+                let key = reference.strip_prefix(prefix)?;
+                may_be_ref = components?.get(key)?.get()?;
+            }
+        }
+    }
+}
+
+fn existing_components(diff: &HttpSchemaDiff) -> Option<&ComponentsDiff> {
+    diff.components.get().filter(|_| diff.components.exists())
+}
+
 pub(crate) fn deref_schema_diff<'a>(
     diff: &'a HttpSchemaDiff,
     may_be_ref: &'a MayBeRefDiff<SchemaDiff>,
 ) -> Option<&'a DiffResult<SchemaDiff>> {
-    match may_be_ref {
-        MayBeRefDiff::Value(value) => return Some(value),
-        MayBeRefDiff::Ref(value) => {
-            if value.reference.starts_with("#/components/schemas/") {
-                let key = value.reference.replace("#/components/schemas/", "");
-                if diff.components.exists() {
-                    if let Some(components) = diff.components.get() {
-                        if let Some(schemas) = components.schemas.get() {
-                            if let Some(may_be_schema) = schemas.get(&key) {
-                                if let Some(MayBeRefDiff::Value(schema)) =
-                                    may_be_schema.get()
-                                {
-                                    return Some(schema);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    };
-    None
+    deref_component_diff(
+        existing_components(diff).and_then(|c| c.schemas.get()),
+        "#/components/schemas/",
+        may_be_ref,
+    )
 }
 
 pub(crate) fn deref_parameter_diff<'a>(
     diff: &'a HttpSchemaDiff,
     may_be_ref: &'a MayBeRefDiff<ParameterDiff>,
 ) -> Option<&'a DiffResult<ParameterDiff>> {
-    match may_be_ref {
-        MayBeRefDiff::Value(value) => return Some(value),
-        MayBeRefDiff::Ref(value) => {
-            if value.reference.starts_with("#/components/parameters/") {
-                let key =
-                    value.reference.replace("#/components/parameters/", "");
-                if diff.components.exists() {
-                    if let Some(components) = diff.components.get() {
-                        if let Some(parameters) = components.parameters.get() {
-                            if let Some(may_be_parameter) =
-                                parameters.get(&key)
-                            {
-                                if let Some(MayBeRefDiff::Value(parameter)) =
-                                    may_be_parameter.get()
-                                {
-                                    return Some(parameter);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    };
-    None
+    deref_component_diff(
+        existing_components(diff).and_then(|c| c.parameters.get()),
+        "#/components/parameters/",
+        may_be_ref,
+    )
 }
 
 pub(crate) fn deref_request_body_diff<'a>(
     diff: &'a HttpSchemaDiff,
     may_be_ref: &'a MayBeRefDiff<RequestBodyDiff>,
 ) -> Option<&'a DiffResult<RequestBodyDiff>> {
-    match may_be_ref {
-        MayBeRefDiff::Value(value) => return Some(value),
-        MayBeRefDiff::Ref(value) => {
-            if value.reference.starts_with("#/components/requestBodies/") {
-                let key =
-                    value.reference.replace("#/components/requestBodies/", "");
-                if diff.components.exists() {
-                    if let Some(components) = diff.components.get() {
-                        if let Some(request_bodies) =
-                            components.request_bodies.get()
-                        {
-                            if let Some(request_body) =
-                                request_bodies.get(&key)
-                            {
-                                if let Some(MayBeRefDiff::Value(
-                                    request_body,
-                                )) = request_body.get()
-                                {
-                                    return Some(request_body);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    };
-    None
+    deref_component_diff(
+        existing_components(diff).and_then(|c| c.request_bodies.get()),
+        "#/components/requestBodies/",
+        may_be_ref,
+    )
 }
 
 pub(crate) fn deref_response_diff<'a>(
     diff: &'a HttpSchemaDiff,
     may_be_ref: &'a MayBeRefDiff<ResponseDiff>,
 ) -> Option<&'a DiffResult<ResponseDiff>> {
-    match may_be_ref {
-        MayBeRefDiff::Value(value) => return Some(value),
-        MayBeRefDiff::Ref(value) => {
-            if value.reference.starts_with("#/components/responses/") {
-                let key =
-                    value.reference.replace("#/components/responses/", "");
-                if diff.components.exists() {
-                    if let Some(components) = diff.components.get() {
-                        if let Some(responses) = components.responses.get() {
-                            if let Some(response) = responses.get(&key) {
-                                if let Some(MayBeRefDiff::Value(response)) =
-                                    response.get()
-                                {
-                                    return Some(response);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    };
-    None
+    deref_component_diff(
+        existing_components(diff).and_then(|c| c.responses.get()),
+        "#/components/responses/",
+        may_be_ref,
+    )
 }
 
 #[cfg(test)]
@@ -815,6 +819,46 @@ mod tests {
       }
         "#;
         let op: Schema = serde_json::from_str(sc_def).unwrap();
-        assert!(matches!(op.discriminator, Some(_)))
+        assert!(op.discriminator.is_some())
+    }
+
+    #[test]
+    fn check_effective_security_diff() {
+        let schema = |security: &str| {
+            format!(
+                r#"{{
+                "openapi": "3.0.3",
+                "security": [{security}],
+                "paths": {{"/users": {{
+                    "get": {{"responses": {{}}}},
+                    "post": {{"security": [], "responses": {{}}}}
+                }}}}
+            }}"#
+            )
+        };
+
+        let (src, tgt) = crate::try_deserialize_schema(
+            &schema(r#"{"apiKey": []}"#),
+            &schema(r#"{"oauth": ["write"]}"#),
+        )
+        .unwrap();
+        let diff = crate::get_schema_diff(src, tgt);
+        let diff = diff.get().unwrap();
+
+        assert!(diff.security.is_updated());
+
+        let path = diff.paths.get().unwrap()["/users"].get().unwrap();
+        let crate::schema_diff::MayBeRefDiff::Value(path) = path else {
+            panic!("path must be a value");
+        };
+        let path = path.get().unwrap();
+
+        let get = path.get.get().unwrap();
+        assert!(get.security.is_updated());
+        assert!(get.security_inherited.is_same());
+
+        let post = path.post.get().unwrap();
+        assert!(post.security.is_same());
+        assert!(post.security_inherited.is_same());
     }
 }
@@ -1,4 +1,6 @@
-use crate::core::{DiffResult, PathResolver, VecDiffTransformer};
+use crate::core::{
+    DiffResult, PathResolver, VecDiffPosition, VecDiffTransformer,
+};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -6,11 +8,13 @@ use std::collections::HashMap;
 #[derive(Debug, Default, Clone)]
 pub struct TypeVecDiffSorter;
 
-impl VecDiffTransformer<Vec<DiffResult<String>>> for TypeVecDiffSorter {
+impl VecDiffTransformer<Vec<(DiffResult<String>, VecDiffPosition)>>
+    for TypeVecDiffSorter
+{
     fn transform(
-        mut vector: Vec<DiffResult<String>>,
-    ) -> Vec<DiffResult<String>> {
-        let index = vector.iter().position(|v| match v.get() {
+        mut vector: Vec<(DiffResult<String>, VecDiffPosition)>,
+    ) -> Vec<(DiffResult<String>, VecDiffPosition)> {
+        let index = vector.iter().position(|(v, _)| match v.get() {
             Some(value) => value == "null",
             None => false,
         });
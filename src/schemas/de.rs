@@ -0,0 +1,165 @@
+use indexmap::IndexMap;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// Map key accepting any scalar value. YAML documents usually keep
+/// response codes unquoted (`200:`), so they are decoded as integers.
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct ScalarKey(String);
+
+impl<'de> Deserialize<'de> for ScalarKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ScalarKeyVisitor;
+
+        impl<'de> Visitor<'de> for ScalarKeyVisitor {
+            type Value = ScalarKey;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string, number or boolean map key")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<ScalarKey, E> {
+                Ok(ScalarKey(v.to_owned()))
+            }
+
+            fn visit_string<E: de::Error>(
+                self,
+                v: String,
+            ) -> Result<ScalarKey, E> {
+                Ok(ScalarKey(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<ScalarKey, E> {
+                Ok(ScalarKey(v.to_string()))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<ScalarKey, E> {
+                Ok(ScalarKey(v.to_string()))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<ScalarKey, E> {
+                Ok(ScalarKey(v.to_string()))
+            }
+
+            fn visit_bool<E: de::Error>(
+                self,
+                v: bool,
+            ) -> Result<ScalarKey, E> {
+                Ok(ScalarKey(v.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(ScalarKeyVisitor)
+    }
+}
+
+/// Deserializes a map whose keys may be any scalar, e.g. response codes
+pub fn scalar_key_map<'de, D, V>(
+    deserializer: D,
+) -> Result<IndexMap<String, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    let map = IndexMap::<ScalarKey, V>::deserialize(deserializer)?;
+    Ok(map.into_iter().map(|(k, v)| (k.0, v)).collect())
+}
+
+/// Same as [`scalar_key_map`] for optional fields
+pub fn optional_scalar_key_map<'de, D, V>(
+    deserializer: D,
+) -> Result<Option<IndexMap<String, V>>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    let map = Option::<IndexMap<ScalarKey, V>>::deserialize(deserializer)?;
+    Ok(map.map(|map| map.into_iter().map(|(k, v)| (k.0, v)).collect()))
+}
+
+/// Walks a document down to the node at a decoder error path, e.g.
+/// `paths./pets.get.tags`, stopping at the deepest node found. The walk
+/// always fails at that node, so the error carries the node position.
+pub struct Locate<'p>(pub &'p str);
+
+impl<'p> Locate<'p> {
+    /// Rest of the path when its next segment is the given key
+    fn descend(&self, key: &str) -> Option<&'p str> {
+        let rest = self.0.strip_prefix(key)?;
+        if rest.is_empty() {
+            Some(rest)
+        } else {
+            rest.strip_prefix('.')
+        }
+    }
+
+    fn found<E: de::Error>() -> E {
+        E::custom("node located")
+    }
+}
+
+impl<'de> de::DeserializeSeed<'de> for Locate<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+// Scalars are rejected by the default `Visitor` methods
+impl<'de> Visitor<'de> for Locate<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a node at `{}`", self.0)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<(), A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        if self.0.is_empty() {
+            return Err(Self::found());
+        }
+
+        while let Some(key) = map.next_key::<ScalarKey>()? {
+            match self.descend(&key.0) {
+                Some(rest) => return map.next_value_seed(Locate(rest)),
+                None => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+
+        Err(Self::found())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        if self.0.is_empty() {
+            return Err(Self::found());
+        }
+
+        let mut index = 0usize;
+        loop {
+            let rest = self.descend(&index.to_string());
+            let next = match rest {
+                Some(rest) => seq.next_element_seed(Locate(rest))?,
+                None => seq.next_element::<de::IgnoredAny>()?.map(|_| ()),
+            };
+            if next.is_none() {
+                return Err(Self::found());
+            }
+            index += 1;
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod de;
 pub mod openapi303;
 pub mod openapi310;
 pub mod swagger2;
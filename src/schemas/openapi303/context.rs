@@ -1,5 +1,6 @@
+use crate::core::deref_component;
 use crate::schemas::openapi303::schema::{
-    Components, Example, Header, Link, MayBeRef303, OpenApi303, Parameter,
+    Callback, Components, Example, Header, Link, OpenApi303, Parameter,
     RequestBody, Response, Schema, SecurityScheme,
 };
 
@@ -7,119 +8,121 @@ pub fn deref_schema<'a>(
     components: &'a Option<Components>,
     reference: &str,
 ) -> Option<&'a Schema> {
-    components.as_ref().and_then(|components| {
-        components.schemas.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/schemas/", ""))
-                .map(|may_be_schema| match may_be_schema {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        components
+            .as_ref()
+            .and_then(|components| components.schemas.as_ref()),
+        "#/components/schemas/",
+        reference,
+    )
 }
 
 pub fn deref_parameter<'a>(
     components: &'a Option<Components>,
     reference: &str,
 ) -> Option<&'a Parameter> {
-    components.as_ref().and_then(|components| {
-        components.parameters.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/parameters/", ""))
-                .map(|may_be_parameter| match may_be_parameter {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        components
+            .as_ref()
+            .and_then(|components| components.parameters.as_ref()),
+        "#/components/parameters/",
+        reference,
+    )
 }
 
 pub fn deref_example<'a>(
     swagger: &'a OpenApi303,
     reference: &str,
 ) -> Option<&'a Example> {
-    swagger.components.as_ref().and_then(|components| {
-        components.examples.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/examples/", ""))
-                .map(|may_be_example| match may_be_example {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        swagger
+            .components
+            .as_ref()
+            .and_then(|components| components.examples.as_ref()),
+        "#/components/examples/",
+        reference,
+    )
 }
 
 pub fn deref_request_body<'a>(
     swagger: &'a OpenApi303,
     reference: &str,
 ) -> Option<&'a RequestBody> {
-    swagger.components.as_ref().and_then(|components| {
-        components.request_bodies.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/requestBodies/", ""))
-                .map(|may_be_request_body| match may_be_request_body {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        swagger
+            .components
+            .as_ref()
+            .and_then(|components| components.request_bodies.as_ref()),
+        "#/components/requestBodies/",
+        reference,
+    )
 }
 
 pub fn deref_header<'a>(
     swagger: &'a OpenApi303,
     reference: &str,
 ) -> Option<&'a Header> {
-    swagger.components.as_ref().and_then(|components| {
-        components.headers.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/headers/", ""))
-                .map(|may_be_header| match may_be_header {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        swagger
+            .components
+            .as_ref()
+            .and_then(|components| components.headers.as_ref()),
+        "#/components/headers/",
+        reference,
+    )
 }
 
 pub fn deref_security_scheme<'a>(
     swagger: &'a OpenApi303,
     reference: &str,
 ) -> Option<&'a SecurityScheme> {
-    swagger.components.as_ref().and_then(|components| {
-        components.security_schemes.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/securitySchemes/", ""))
-                .map(|may_be_security_scheme| match may_be_security_scheme {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        swagger
+            .components
+            .as_ref()
+            .and_then(|components| components.security_schemes.as_ref()),
+        "#/components/securitySchemes/",
+        reference,
+    )
 }
 
 pub fn deref_link<'a>(
     swagger: &'a OpenApi303,
     reference: &str,
 ) -> Option<&'a Link> {
-    swagger.components.as_ref().and_then(|components| {
-        components.links.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/links/", "")).map(
-                |may_be_link| match may_be_link {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                },
-            )
-        })
-    })
+    deref_component(
+        swagger
+            .components
+            .as_ref()
+            .and_then(|components| components.links.as_ref()),
+        "#/components/links/",
+        reference,
+    )
 }
 
 pub fn deref_response<'a>(
     swagger: &'a OpenApi303,
     reference: &str,
 ) -> Option<&'a Response> {
-    swagger.components.as_ref().and_then(|components| {
-        components.responses.as_ref().and_then(|map| {
-            map.get(&reference.replace("#/components/responses/", ""))
-                .map(|may_be_response| match may_be_response {
-                    MayBeRef303::Value(value) => value,
-                    _ => unimplemented!(),
-                })
-        })
-    })
+    deref_component(
+        swagger
+            .components
+            .as_ref()
+            .and_then(|components| components.responses.as_ref()),
+        "#/components/responses/",
+        reference,
+    )
+}
+
+pub fn deref_callback<'a>(
+    components: &'a Option<Components>,
+    reference: &str,
+) -> Option<&'a Callback> {
+    deref_component(
+        components
+            .as_ref()
+            .and_then(|components| components.callbacks.as_ref()),
+        "#/components/callbacks/",
+        reference,
+    )
 }
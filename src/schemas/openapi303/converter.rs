@@ -3,15 +3,17 @@ use serde_json::Value;
 
 use crate::core::{Either, ReferenceDescriptor};
 
+use crate::diagnostic::check_references;
 use crate::schema as core;
 
 use crate::schemas::openapi303::context::*;
 use crate::schemas::openapi303::schema::*;
 
-pub const VERSION: &str = "0.1.0";
+pub const VERSION: &str = "0.2.0";
 
 struct ConvertContext<'a> {
     pub components: &'a Option<Components>,
+    pub security: &'a Option<Vec<IndexMap<String, Vec<String>>>>,
 }
 
 impl From<OpenApi303> for core::HttpSchema {
@@ -19,6 +21,7 @@ impl From<OpenApi303> for core::HttpSchema {
         let paths = {
             let context = ConvertContext {
                 components: &spec.components,
+                security: &spec.security,
             };
             spec.paths.map(|paths| convert_paths(paths, &context))
         };
@@ -127,7 +130,7 @@ impl From<OpenApi303> for core::HttpSchema {
             .servers
             .map(|servers| servers.into_iter().map(convert_server).collect());
 
-        core::HttpSchema {
+        let mut schema = core::HttpSchema {
             version: spec.openapi,
 
             schema_source: OpenApi303::id().to_owned(),
@@ -137,10 +140,16 @@ impl From<OpenApi303> for core::HttpSchema {
             info,
             servers,
             paths,
+            webhooks: None,
             components,
+            security: spec.security,
             tags,
             external_docs,
-        }
+            diagnostics: Vec::new(),
+        };
+
+        schema.diagnostics = check_references(&schema);
+        schema
     }
 }
 
@@ -233,8 +242,8 @@ fn merge_parameters(
                 {
                     (parameter.name.clone(), parameter.r#in.clone())
                 } else {
-                    // TODO: handle the case where ref not found
-                    continue;
+                    // Keep dangling refs so they are reported as diagnostics
+                    (value.reference().to_owned(), String::new())
                 }
             }
             MayBeRef303::Value(value) => {
@@ -259,11 +268,8 @@ fn convert_operation(
     path_parameters: &Option<Vec<MayBeRef303<Parameter>>>,
     context: &ConvertContext,
 ) -> core::Operation {
-    let merged_parameters = merge_parameters(
-        context, // TODO: pass components
-        operation.parameters,
-        path_parameters,
-    );
+    let merged_parameters =
+        merge_parameters(context, operation.parameters, path_parameters);
 
     let parameters = merged_parameters
         .into_iter()
@@ -287,6 +293,19 @@ fn convert_operation(
         .servers
         .map(|servers| servers.into_iter().map(convert_server).collect());
 
+    let (security, security_inherited) =
+        core::effective_security(operation.security, context.security);
+
+    let callbacks = operation.callbacks.map(|callbacks| {
+        callbacks
+            .into_iter()
+            .filter_map(|(name, callback_ref)| {
+                convert_callback_ref(callback_ref, context)
+                    .map(|callback| (name, callback))
+            })
+            .collect()
+    });
+
     core::Operation {
         tags: operation.tags,
         summary: operation.summary,
@@ -297,11 +316,29 @@ fn convert_operation(
         request_body,
         servers,
         parameters: Some(parameters),
-        security: operation.security,
+        security,
+        security_inherited,
+        callbacks,
         deprecated: operation.deprecated,
+        custom_fields: operation.custom_fields,
     }
 }
 
+/// Callbacks have no counterpart in core components, so refs are inlined
+fn convert_callback_ref(
+    callback_ref: MayBeRef303<Callback>,
+    context: &ConvertContext,
+) -> Option<core::Callback> {
+    let callback = match callback_ref {
+        MayBeRef303::Ref(value) => {
+            deref_callback(context.components, value.reference())?.clone()
+        }
+        MayBeRef303::Value(value) => value,
+    };
+
+    Some(convert_paths(callback, context))
+}
+
 fn convert_request_body_ref(
     request_body_ref: MayBeRef303<RequestBody>,
 ) -> core::MayBeRef<core::RequestBody> {
@@ -523,11 +560,13 @@ fn convert_schema(schema: Schema) -> core::Schema {
                     Some(Either::Right(values))
                 }
             }
-        } else {
+        } else if nullable {
             Some(Either::Right(Box::new(vec![
                 "object".to_string(),
                 "null".to_string(),
             ])))
+        } else {
+            None
         }
     } else {
         schema.r#type
@@ -584,9 +623,9 @@ fn convert_schema(schema: Schema) -> core::Schema {
         title: schema.title,
         multiple_of: schema.multiple_of,
         maximum: schema.maximum,
-        exclusive_maximum: schema.exclusive_maximum,
+        exclusive_maximum: schema.exclusive_maximum.map(Either::Left),
         minimum: schema.minimum,
-        exclusive_minimum: schema.exclusive_minimum,
+        exclusive_minimum: schema.exclusive_minimum.map(Either::Left),
         max_length: schema.max_length,
         min_length: schema.min_length,
         pattern: schema.pattern,
@@ -616,6 +655,7 @@ fn convert_schema(schema: Schema) -> core::Schema {
         example: schema.example,
         deprecated: schema.deprecated,
         custom_fields: schema.custom_fields,
+        ..Default::default()
     }
 }
 
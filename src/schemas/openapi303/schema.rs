@@ -40,8 +40,7 @@ pub struct OpenApi303 {
     pub servers: Option<Vec<Server>>,
     pub paths: Option<IndexMap<String, MayBeRef303<Path>>>,
     pub components: Option<Components>,
-    // TODO:
-    // pub security:
+    pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
     pub tags: Option<Vec<Tag>>,
     pub external_docs: Option<ExternalDoc>,
 }
@@ -104,7 +103,7 @@ pub struct Components {
     pub security_schemes:
         Option<IndexMap<String, MayBeRef303<SecurityScheme>>>,
     pub links: Option<IndexMap<String, MayBeRef303<Link>>>,
-    // pub callbacks: Option<HashMap<String, MayBeRef303<Header>>>,
+    pub callbacks: Option<IndexMap<String, MayBeRef303<Callback>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -334,6 +333,10 @@ pub struct Operation {
 
     pub parameters: Option<Vec<MayBeRef303<Parameter>>>,
 
+    #[serde(
+        default,
+        deserialize_with = "crate::schemas::de::optional_scalar_key_map"
+    )]
     pub responses: Option<IndexMap<String, MayBeRef303<Response>>>,
 
     pub request_body: Option<MayBeRef303<RequestBody>>,
@@ -341,11 +344,16 @@ pub struct Operation {
 
     pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
 
-    // TODO:
-    // pub callbacks
+    pub callbacks: Option<IndexMap<String, MayBeRef303<Callback>>>,
     pub deprecated: Option<bool>,
+
+    #[serde(flatten)]
+    pub custom_fields: IndexMap<String, Value>,
 }
 
+/// Path items keyed by the runtime expression of the callback url
+pub type Callback = IndexMap<String, MayBeRef303<Path>>;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Path {
     pub get: Option<Operation>,
@@ -482,7 +490,7 @@ mod tests {
       }
         "#;
         let op: Schema = serde_json::from_str(sc_def).unwrap();
-        assert!(matches!(op.discriminator, Some(_)))
+        assert!(op.discriminator.is_some())
     }
 
     #[test]
@@ -3,24 +3,31 @@ use serde_json::Value;
 
 use crate::core::{Either, ReferenceDescriptor};
 
+use crate::diagnostic::check_references;
 use crate::schema as core;
 
 use crate::schemas::openapi310::context::*;
 use crate::schemas::openapi310::schema::*;
 
-pub const VERSION: &str = "0.1.0";
+pub const VERSION: &str = "0.2.0";
 
 struct ConvertContext<'a> {
     pub components: &'a Option<Components>,
+    pub security: &'a Option<Vec<IndexMap<String, Vec<String>>>>,
 }
 
 impl From<OpenApi310> for core::HttpSchema {
     fn from(spec: OpenApi310) -> Self {
-        let paths = {
+        let (paths, webhooks) = {
             let context = ConvertContext {
                 components: &spec.components,
+                security: &spec.security,
             };
-            spec.paths.map(|paths| convert_paths(paths, &context))
+            (
+                spec.paths.map(|paths| convert_paths(paths, &context)),
+                spec.webhooks
+                    .map(|webhooks| convert_paths(webhooks, &context)),
+            )
         };
 
         let components = if let Some(components) = spec.components {
@@ -127,7 +134,7 @@ impl From<OpenApi310> for core::HttpSchema {
             .servers
             .map(|servers| servers.into_iter().map(convert_server).collect());
 
-        core::HttpSchema {
+        let mut schema = core::HttpSchema {
             version: spec.openapi,
             schema_source: OpenApi310::id().to_owned(),
             schema_source_version: VERSION.to_owned(),
@@ -135,10 +142,16 @@ impl From<OpenApi310> for core::HttpSchema {
             info,
             servers,
             paths,
+            webhooks,
             components,
+            security: spec.security,
             tags,
             external_docs,
-        }
+            diagnostics: Vec::new(),
+        };
+
+        schema.diagnostics = check_references(&schema);
+        schema
     }
 }
 
@@ -231,8 +244,8 @@ fn merge_parameters(
                 {
                     (parameter.name.clone(), parameter.r#in.clone())
                 } else {
-                    // TODO: handle the case where ref not found
-                    continue;
+                    // Keep dangling refs so they are reported as diagnostics
+                    (value.reference().to_owned(), String::new())
                 }
             }
             MayBeRef310::Value(value) => {
@@ -257,11 +270,8 @@ fn convert_operation(
     path_parameters: &Option<Vec<MayBeRef310<Parameter>>>,
     context: &ConvertContext,
 ) -> core::Operation {
-    let merged_parameters = merge_parameters(
-        context, // TODO: pass components
-        operation.parameters,
-        path_parameters,
-    );
+    let merged_parameters =
+        merge_parameters(context, operation.parameters, path_parameters);
 
     let parameters = merged_parameters
         .into_iter()
@@ -285,6 +295,19 @@ fn convert_operation(
         .servers
         .map(|servers| servers.into_iter().map(convert_server).collect());
 
+    let (security, security_inherited) =
+        core::effective_security(operation.security, context.security);
+
+    let callbacks = operation.callbacks.map(|callbacks| {
+        callbacks
+            .into_iter()
+            .filter_map(|(name, callback_ref)| {
+                convert_callback_ref(callback_ref, context)
+                    .map(|callback| (name, callback))
+            })
+            .collect()
+    });
+
     core::Operation {
         tags: operation.tags,
         summary: operation.summary,
@@ -295,11 +318,29 @@ fn convert_operation(
         request_body,
         servers,
         parameters: Some(parameters),
-        security: operation.security,
+        security,
+        security_inherited,
+        callbacks,
         deprecated: operation.deprecated,
+        custom_fields: operation.custom_fields,
     }
 }
 
+/// Callbacks have no counterpart in core components, so refs are inlined
+fn convert_callback_ref(
+    callback_ref: MayBeRef310<Callback>,
+    context: &ConvertContext,
+) -> Option<core::Callback> {
+    let callback = match callback_ref {
+        MayBeRef310::Ref(value) => {
+            deref_callback(context.components, value.reference())?.clone()
+        }
+        MayBeRef310::Value(value) => value,
+    };
+
+    Some(convert_paths(callback, context))
+}
+
 fn convert_request_body_ref(
     request_body_ref: MayBeRef310<RequestBody>,
 ) -> core::MayBeRef<core::RequestBody> {
@@ -533,6 +574,30 @@ fn convert_schema(schema: Schema) -> core::Schema {
             }
         });
 
+    let prefix_items = schema
+        .prefix_items
+        .map(|values| values.into_iter().map(convert_schema_ref).collect());
+
+    let contains = schema.contains.map(convert_schema_ref);
+    let r#if = schema.r#if.map(convert_schema_ref);
+    let then = schema.then.map(convert_schema_ref);
+    let r#else = schema.r#else.map(convert_schema_ref);
+    let property_names = schema.property_names.map(convert_schema_ref);
+
+    let dependent_schemas = schema.dependent_schemas.map(convert_schema_map);
+    let pattern_properties = schema.pattern_properties.map(convert_schema_map);
+    let defs = schema.defs.map(convert_schema_map);
+
+    let unevaluated_properties =
+        schema.unevaluated_properties.map(|unevaluated_properties| {
+            match unevaluated_properties {
+                Either::Left(value) => Either::Left(value),
+                Either::Right(schema_ref) => {
+                    Either::Right(Box::new(convert_schema_ref(*schema_ref)))
+                }
+            }
+        });
+
     let discriminator =
         schema
             .discriminator
@@ -580,10 +645,34 @@ fn convert_schema(schema: Schema) -> core::Schema {
         external_docs,
         example: schema.example,
         deprecated: schema.deprecated,
+        defs,
+        r#if: Box::new(r#if),
+        then: Box::new(then),
+        r#else: Box::new(r#else),
+        dependent_schemas,
+        r#const: schema.r#const,
+        contains: Box::new(contains),
+        min_contains: schema.min_contains,
+        max_contains: schema.max_contains,
+        dependent_required: schema.dependent_required,
+        prefix_items,
+        pattern_properties,
+        property_names: Box::new(property_names),
+        unevaluated_properties,
+        examples: schema.examples,
         custom_fields: schema.custom_fields,
     }
 }
 
+fn convert_schema_map(
+    schemas: IndexMap<String, MayBeRef310<Schema>>,
+) -> IndexMap<String, core::MayBeRef<core::Schema>> {
+    schemas
+        .into_iter()
+        .map(|(key, schema_ref)| (key, convert_schema_ref(schema_ref)))
+        .collect()
+}
+
 fn convert_external_doc(external_doc: ExternalDoc) -> core::ExternalDoc {
     core::ExternalDoc {
         url: external_doc.url,
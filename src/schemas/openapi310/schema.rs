@@ -39,9 +39,9 @@ pub struct OpenApi310 {
     pub info: Option<Info>,
     pub servers: Option<Vec<Server>>,
     pub paths: Option<IndexMap<String, MayBeRef310<Path>>>,
+    pub webhooks: Option<IndexMap<String, MayBeRef310<Path>>>,
     pub components: Option<Components>,
-    // TODO:
-    // pub security:
+    pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
     pub tags: Option<Vec<Tag>>,
     pub external_docs: Option<ExternalDoc>,
 }
@@ -104,7 +104,7 @@ pub struct Components {
     pub security_schemes:
         Option<IndexMap<String, MayBeRef310<SecurityScheme>>>,
     pub links: Option<IndexMap<String, MayBeRef310<Link>>>,
-    // pub callbacks: Option<HashMap<String, MayBeRef310<Header>>>,
+    pub callbacks: Option<IndexMap<String, MayBeRef310<Callback>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -270,24 +270,22 @@ pub struct Schema {
     // #[serde(rename = "$dynamicRef")]
     // dynamic_ref_: Option<String>,
     //
-    // #[serde(rename = "$def")]
-    // defs_: Option<HashMap<String, Schema>>,
-    //
+    #[serde(rename = "$defs")]
+    pub defs: Option<IndexMap<String, MayBeRef310<Schema>>>,
+
     // #[serde(rename = "$comment")]
     // comment_: Option<HashMap<String, Schema>>,
+    pub r#if: Box<Option<MayBeRef310<Schema>>>,
+    pub then: Box<Option<MayBeRef310<Schema>>>,
+    pub r#else: Box<Option<MayBeRef310<Schema>>>,
+    pub dependent_schemas: Option<IndexMap<String, MayBeRef310<Schema>>>,
 
-    // if
-    // then
-    // else
-    // dependentSchemas
-
-    // contains
     pub title: Option<String>,
     pub multiple_of: Option<f32>,
     pub maximum: Option<f32>,
-    pub exclusive_maximum: Option<bool>,
+    pub exclusive_maximum: Option<Either<bool, f32>>,
     pub minimum: Option<f32>,
-    pub exclusive_minimum: Option<bool>,
+    pub exclusive_minimum: Option<Either<bool, f32>>,
     pub max_length: Option<usize>,
     pub min_length: Option<usize>,
     pub pattern: Option<String>,
@@ -299,13 +297,13 @@ pub struct Schema {
     pub required: Option<Vec<String>>,
     pub r#enum: Option<Vec<Value>>,
 
-    // r#const: Option<Vec>,
+    pub r#const: Option<Value>,
 
-    // pub contains: Box<Option<MayBeRef310<Schema>>>
-    // pub min_contains: Option<usize>,
-    // pub max_contains: Option<bool>,
+    pub contains: Box<Option<MayBeRef310<Schema>>>,
+    pub min_contains: Option<usize>,
+    pub max_contains: Option<usize>,
 
-    // dependentRequired
+    pub dependent_required: Option<IndexMap<String, Vec<String>>>,
 
     // https://json-schema.org/draft/2020-12/json-schema-validation.html#:~:text=to%20these%20keywords.-,8.3.,-contentEncoding
     // content_encoding
@@ -319,16 +317,17 @@ pub struct Schema {
 
     pub items: Box<Option<MayBeRef310<Schema>>>,
 
-    // prefix_items: Option<Vec<MayBeRef310<Schema>>>,
+    pub prefix_items: Option<Vec<MayBeRef310<Schema>>>,
 
     // unevaluated_items: Box<Option<MayBeRef310<Schema>>>
     pub properties: Option<IndexMap<String, MayBeRef310<Schema>>>,
     pub additional_properties: Option<Either<bool, MayBeRef310<Schema>>>, // TODO: Can be Bool in 3.1??
-    // pattern_properties: Option<HashMap<String, MayBeRef310<Schema>>>,
+    pub pattern_properties: Option<IndexMap<String, MayBeRef310<Schema>>>,
+
+    pub property_names: Box<Option<MayBeRef310<Schema>>>,
 
-    // property_names: Box<Option<MayBeRef310<Schema>>>,
+    pub unevaluated_properties: Option<Either<bool, MayBeRef310<Schema>>>,
 
-    // unevaluated_properties: Box<Option<MayBeRef310<Schema>>>
     pub description: Option<String>,
     pub format: Option<String>,
     pub default: Option<Value>,
@@ -340,7 +339,7 @@ pub struct Schema {
     pub external_docs: Option<ExternalDoc>,
     pub example: Option<Value>,
 
-    // examples: Option<Vec<Value>>,
+    pub examples: Option<Vec<Value>>,
     pub deprecated: Option<bool>,
 
     #[serde(flatten)]
@@ -384,6 +383,10 @@ pub struct Operation {
 
     pub parameters: Option<Vec<MayBeRef310<Parameter>>>,
 
+    #[serde(
+        default,
+        deserialize_with = "crate::schemas::de::optional_scalar_key_map"
+    )]
     pub responses: Option<IndexMap<String, MayBeRef310<Response>>>,
 
     pub request_body: Option<MayBeRef310<RequestBody>>,
@@ -391,11 +394,16 @@ pub struct Operation {
 
     pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
 
-    // TODO:
-    // pub callbacks
+    pub callbacks: Option<IndexMap<String, MayBeRef310<Callback>>>,
     pub deprecated: Option<bool>,
+
+    #[serde(flatten)]
+    pub custom_fields: IndexMap<String, Value>,
 }
 
+/// Path items keyed by the runtime expression of the callback url
+pub type Callback = IndexMap<String, MayBeRef310<Path>>;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Path {
     pub get: Option<Operation>,
@@ -543,6 +551,6 @@ mod tests {
       }
         "#;
         let op: Schema = serde_json::from_str(sc_def).unwrap();
-        assert!(matches!(op.discriminator, Some(_)))
+        assert!(op.discriminator.is_some())
     }
 }
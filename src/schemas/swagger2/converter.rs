@@ -1,12 +1,13 @@
 use crate::core::{Either, ReferenceDescriptor};
 use indexmap::IndexMap;
 
+use crate::diagnostic::check_references;
 use crate::schema as core;
 
 use crate::schemas::swagger2::context::*;
 use crate::schemas::swagger2::schema::*;
 
-pub const VERSION: &str = "0.1.0";
+pub const VERSION: &str = "0.2.0";
 
 struct ConvertContext<'a> {
     pub consumes: &'a Option<Vec<String>>,
@@ -14,6 +15,10 @@ struct ConvertContext<'a> {
     // pub definitions: &'a Option<IndexMap<String, V2Schema>>,
     pub parameters: &'a Option<IndexMap<String, Parameter>>,
     pub responses: &'a Option<IndexMap<String, Response>>,
+    pub security: &'a Option<Vec<IndexMap<String, Vec<String>>>>,
+    pub host: &'a Option<String>,
+    pub base_path: &'a Option<String>,
+    pub schemes: &'a Option<Vec<String>>,
 }
 
 impl From<SwaggerV2> for core::HttpSchema {
@@ -25,6 +30,10 @@ impl From<SwaggerV2> for core::HttpSchema {
                 // definitions: &spec.definitions,
                 parameters: &spec.parameters,
                 responses: &spec.responses,
+                security: &spec.security,
+                host: &spec.host,
+                base_path: &spec.base_path,
+                schemes: &spec.schemes,
             };
 
             spec.paths.map(|paths| convert_paths(paths, &context))
@@ -63,6 +72,21 @@ impl From<SwaggerV2> for core::HttpSchema {
                 .collect::<IndexMap<_, _>>()
         });
 
+        let security_schemes =
+            spec.security_definitions.map(|security_definitions| {
+                security_definitions
+                    .into_iter()
+                    .map(|(key, security_scheme)| {
+                        (
+                            key,
+                            core::MayBeRef::Value(convert_security_scheme(
+                                security_scheme,
+                            )),
+                        )
+                    })
+                    .collect::<IndexMap<_, _>>()
+            });
+
         let components = core::Components {
             schemas,
             responses,
@@ -70,13 +94,16 @@ impl From<SwaggerV2> for core::HttpSchema {
             examples: None,
             request_bodies: None,
             headers: None,
-            security_schemes: None,
+            security_schemes,
             links: None,
         };
 
         let info = spec.info.map(convert_info);
 
-        core::HttpSchema {
+        let servers =
+            convert_servers(&spec.schemes, &spec.host, &spec.base_path);
+
+        let mut schema = core::HttpSchema {
             version: spec.swagger,
 
             schema_source: SwaggerV2::id().to_owned(),
@@ -84,12 +111,18 @@ impl From<SwaggerV2> for core::HttpSchema {
             schema_version: core::HttpSchema::schema_version().to_owned(),
 
             info,
-            servers: None,
+            servers,
             paths,
+            webhooks: None,
             components: Some(components),
+            security: spec.security,
             tags: None,
             external_docs: None,
-        }
+            diagnostics: Vec::new(),
+        };
+
+        schema.diagnostics = check_references(&schema);
+        schema
     }
 }
 
@@ -137,6 +170,46 @@ fn convert_path(path: Path, context: &ConvertContext) -> core::Path {
     }
 }
 
+/// Synthesises OpenAPI 3 servers from `schemes`, `host` and `basePath`,
+/// one server per scheme
+fn convert_servers(
+    schemes: &Option<Vec<String>>,
+    host: &Option<String>,
+    base_path: &Option<String>,
+) -> Option<Vec<core::Server>> {
+    let base_path = base_path
+        .as_deref()
+        .map(|base_path| base_path.trim_end_matches('/'))
+        .unwrap_or_default();
+
+    let Some(host) = host else {
+        if base_path.is_empty() {
+            return None;
+        }
+        return Some(vec![convert_server(base_path.to_owned())]);
+    };
+
+    let servers = match schemes {
+        Some(schemes) if !schemes.is_empty() => schemes
+            .iter()
+            .map(|scheme| {
+                convert_server(format!("{scheme}://{host}{base_path}"))
+            })
+            .collect(),
+        _ => vec![convert_server(format!("//{host}{base_path}"))],
+    };
+
+    Some(servers)
+}
+
+fn convert_server(url: String) -> core::Server {
+    core::Server {
+        url: Some(url),
+        description: None,
+        variables: None,
+    }
+}
+
 fn convert_info(info: Info) -> core::Info {
     core::Info {
         title: info.title,
@@ -190,8 +263,8 @@ fn merge_parameters(
                 {
                     (parameter.name.clone(), parameter.r#in.clone())
                 } else {
-                    // TODO: handle the case where ref not found
-                    continue;
+                    // Keep dangling refs so they are reported as diagnostics
+                    (value.reference().to_owned(), String::new())
                 }
             }
             MayBeRef200::Value(value) => {
@@ -217,7 +290,7 @@ fn convert_operation(
     context: &ConvertContext,
 ) -> core::Operation {
     let merged_parameters = merge_parameters(
-        context.parameters, // TODO: pass components
+        context.parameters,
         operation.parameters,
         path_parameters,
     );
@@ -227,18 +300,28 @@ fn convert_operation(
     }
 
     let mut body_parameters: Vec<_> = Vec::with_capacity(1);
+    let mut form_parameters: Vec<_> = Vec::new();
     let mut parameters: Vec<_> = Vec::with_capacity(merged_parameters.len());
 
     for (_name, loc, parameter) in merged_parameters {
-        if loc == "body" || loc == "formData" {
+        if loc == "body" {
             body_parameters.push(parameter)
+        } else if loc == "formData" {
+            form_parameters.push(parameter)
         } else {
             parameters.push(convert_parameter_ref(parameter))
         }
     }
 
     let request_body =
-        convert_to_request_body(body_parameters, &operation.consumes, context);
+        convert_to_request_body(body_parameters, &operation.consumes, context)
+            .or_else(|| {
+                convert_form_data_to_request_body(
+                    form_parameters,
+                    &operation.consumes,
+                    context,
+                )
+            });
 
     let mut produces = operation
         .produces
@@ -267,6 +350,16 @@ fn convert_operation(
 
     let external_docs = operation.external_docs.map(convert_external_docs);
 
+    let (security, security_inherited) =
+        core::effective_security(operation.security, context.security);
+
+    let servers = operation
+        .schemes
+        .filter(|schemes| Some(schemes) != context.schemes.as_ref())
+        .and_then(|schemes| {
+            convert_servers(&Some(schemes), context.host, context.base_path)
+        });
+
     core::Operation {
         tags: Some(operation.tags),
         summary: operation.summary,
@@ -275,10 +368,13 @@ fn convert_operation(
         operation_id: operation.operation_id,
         responses: Some(responses),
         request_body,
-        servers: None,
+        servers,
         parameters: Some(parameters),
-        security: None, // TODO: add security policies
+        security,
+        security_inherited,
+        callbacks: None,
         deprecated: operation.deprecated,
+        custom_fields: operation.custom_fields,
     }
 }
 
@@ -344,6 +440,125 @@ fn convert_to_request_body(
     }
 }
 
+/// Merges `formData` parameters into a single object schema sent as
+/// `application/x-www-form-urlencoded` or `multipart/form-data`
+fn convert_form_data_to_request_body(
+    parameters: Vec<MayBeRef200<Parameter>>,
+    consumes: &Option<Vec<String>>,
+    context: &ConvertContext,
+) -> Option<core::MayBeRef<core::RequestBody>> {
+    let parameters: Vec<_> = parameters
+        .into_iter()
+        .filter_map(|parameter| match parameter {
+            MayBeRef200::Ref(value) => {
+                deref_parameter(context.parameters, value.reference()).cloned()
+            }
+            MayBeRef200::Value(value) => Some(value),
+        })
+        .collect();
+
+    if parameters.is_empty() {
+        return None;
+    }
+
+    let has_files = parameters.iter().any(|parameter| {
+        matches!(&parameter.r#type, Some(Either::Left(t)) if t == "file")
+    });
+
+    let mut properties = IndexMap::with_capacity(parameters.len());
+    let mut required = Vec::new();
+    let mut encoding = IndexMap::new();
+
+    for parameter in parameters {
+        let parameter = convert_parameter(parameter);
+
+        if parameter.required == Some(true) {
+            required.push(parameter.name.clone());
+        }
+
+        if parameter.style.is_some() {
+            encoding.insert(
+                parameter.name.clone(),
+                core::Encoding {
+                    content_type: None,
+                    headers: None,
+                    style: parameter.style,
+                    explode: parameter.explode,
+                    allow_reserved: None,
+                },
+            );
+        }
+
+        let schema = parameter.schema.map(|schema| match schema {
+            core::MayBeRef::Value(mut schema) => {
+                if matches!(&schema.r#type, Some(Either::Left(t)) if t == "file")
+                {
+                    schema.r#type = Some(Either::Left("string".to_owned()));
+                    schema.format = Some("binary".to_owned());
+                }
+                if schema.description.is_none() {
+                    schema.description = parameter.description;
+                }
+                core::MayBeRef::Value(schema)
+            }
+            schema => schema,
+        });
+
+        if let Some(schema) = schema {
+            properties.insert(parameter.name, schema);
+        }
+    }
+
+    let mut media_types: Vec<_> = consumes
+        .as_ref()
+        .or(context.consumes.as_ref())
+        .map(|consumes| {
+            consumes
+                .iter()
+                .filter(|media_type| {
+                    *media_type == "application/x-www-form-urlencoded"
+                        || *media_type == "multipart/form-data"
+                })
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if media_types.is_empty() {
+        media_types.push(if has_files {
+            "multipart/form-data".to_owned()
+        } else {
+            "application/x-www-form-urlencoded".to_owned()
+        });
+    }
+
+    let request_required = !required.is_empty();
+
+    let schema = core::Schema {
+        r#type: Some(Either::Left("object".to_owned())),
+        properties: Some(properties),
+        required: request_required.then_some(required),
+        ..Default::default()
+    };
+
+    let media_type = core::MediaType {
+        schema: Some(core::MayBeRef::Value(schema)),
+        examples: None,
+        encoding: (!encoding.is_empty()).then_some(encoding),
+    };
+
+    let content = media_types
+        .into_iter()
+        .map(|media_type_name| (media_type_name, media_type.clone()))
+        .collect();
+
+    Some(core::MayBeRef::Value(core::RequestBody {
+        content: Some(content),
+        required: request_required.then_some(true),
+        description: None,
+    }))
+}
+
 fn convert_response_ref(
     response_ref: MayBeRef200<Response>,
     produces: &[String],
@@ -352,16 +567,17 @@ fn convert_response_ref(
 ) -> core::MayBeRef<core::Response> {
     match response_ref {
         MayBeRef200::Ref(value) => {
-            if unref {
-                let reference = value.reference.replace("#/responses/", "");
-
-                let response = context
-                    .responses
-                    .as_ref()
-                    .and_then(|responses| responses.get(&reference).cloned())
-                    .unwrap();
-
-                core::MayBeRef::Value(convert_response(response, produces))
+            let reference = value.reference.replace("#/responses/", "");
+            let response = context
+                .responses
+                .as_ref()
+                .and_then(|responses| responses.get(&reference));
+
+            if let (true, Some(response)) = (unref, response) {
+                core::MayBeRef::Value(convert_response(
+                    response.clone(),
+                    produces,
+                ))
             } else {
                 core::MayBeRef::Ref(core::HttpSchemaRef {
                     reference: value
@@ -420,14 +636,18 @@ fn convert_schema_to_media_type(
 fn convert_header(header: Header) -> core::Header {
     let items = header.items.map(convert_schema_ref);
 
-    let explode = header.format.as_ref().map(|format| format == "multi");
+    let (style, explode) = convert_collection_format(
+        header.collection_format.as_deref(),
+        header.r#type == "array",
+        "header",
+    );
 
     let schema = core::Schema {
         multiple_of: header.multiple_of,
         maximum: header.maximum,
-        exclusive_maximum: header.exclusive_maximum,
+        exclusive_maximum: header.exclusive_maximum.map(Either::Left),
         minimum: header.minimum,
-        exclusive_minimum: header.exclusive_minimum,
+        exclusive_minimum: header.exclusive_minimum.map(Either::Left),
         max_length: header.max_length,
         min_length: header.min_length,
         pattern: header.pattern,
@@ -442,15 +662,13 @@ fn convert_header(header: Header) -> core::Header {
         ..Default::default()
     };
 
-    // collectionFormat in ('ssv', 'pipes', 'tsv') not supported
-
     core::Header {
         schema: Some(core::MayBeRef::Value(schema)),
         description: header.description,
         required: None,
         deprecated: None,
         allow_empty_value: None,
-        style: None,
+        style,
         explode,
         allow_reserved: None,
         examples: None,
@@ -475,6 +693,18 @@ fn convert_parameter_ref(
 }
 
 fn convert_parameter(parameter: Parameter) -> core::Parameter {
+    let is_array = match &parameter.r#type {
+        Some(Either::Left(r#type)) => r#type == "array",
+        Some(Either::Right(types)) => types.iter().any(|t| t == "array"),
+        None => false,
+    };
+
+    let (style, explode) = convert_collection_format(
+        parameter.collection_format.as_deref(),
+        is_array,
+        &parameter.r#in,
+    );
+
     let schema = if let Some(schema) = parameter.schema.map(convert_schema_ref)
     {
         Some(schema)
@@ -499,9 +729,9 @@ fn convert_parameter(parameter: Parameter) -> core::Parameter {
             title: None,
             multiple_of: None,
             maximum: parameter.maximum,
-            exclusive_maximum: parameter.exclusive_maximum,
+            exclusive_maximum: parameter.exclusive_maximum.map(Either::Left),
             minimum: parameter.minimum,
-            exclusive_minimum: parameter.exclusive_minimum,
+            exclusive_minimum: parameter.exclusive_minimum.map(Either::Left),
             max_length: parameter.max_length,
             min_length: parameter.min_length,
             pattern: parameter.pattern,
@@ -533,13 +763,12 @@ fn convert_parameter(parameter: Parameter) -> core::Parameter {
             example: None,
             deprecated: None,
             custom_fields: Default::default(),
+            ..Default::default()
         };
 
         Some(core::MayBeRef::Value(schema))
     };
 
-    // TODO: add collection_format
-
     core::Parameter {
         name: parameter.name,
         r#in: parameter.r#in,
@@ -547,8 +776,8 @@ fn convert_parameter(parameter: Parameter) -> core::Parameter {
         required: parameter.required,
         deprecated: None,
         allow_empty_value: parameter.allow_empty_value,
-        style: None,
-        explode: None,
+        style,
+        explode,
         allow_reserved: None,
         schema,
         examples: None,
@@ -557,6 +786,33 @@ fn convert_parameter(parameter: Parameter) -> core::Parameter {
     }
 }
 
+/// Maps `collectionFormat` to `style` and `explode`, arrays default to `csv`.
+/// `tsv` has no OpenAPI 3 equivalent.
+fn convert_collection_format(
+    collection_format: Option<&str>,
+    is_array: bool,
+    location: &str,
+) -> (Option<String>, Option<bool>) {
+    let collection_format = match collection_format {
+        Some(collection_format) => collection_format,
+        None if is_array => "csv",
+        None => return (None, None),
+    };
+
+    let form = location == "query" || location == "formData";
+
+    let (style, explode) = match collection_format {
+        "csv" if form => ("form", false),
+        "csv" => ("simple", false),
+        "ssv" => ("spaceDelimited", false),
+        "pipes" => ("pipeDelimited", false),
+        "multi" => ("form", true),
+        _ => return (None, None),
+    };
+
+    (Some(style.to_owned()), Some(explode))
+}
+
 fn convert_schema_ref(
     schema_ref: MayBeRef200<Schema>,
 ) -> core::MayBeRef<core::Schema> {
@@ -573,6 +829,29 @@ fn convert_schema_ref(
 }
 
 fn convert_schema(schema: Schema) -> core::Schema {
+    let mut custom_fields = schema.custom_fields;
+
+    // `x-nullable` is the common vendor spelling of OAS 3.0 `nullable`
+    let nullable = custom_fields
+        .get("x-nullable")
+        .and_then(|value| value.as_bool());
+    if nullable.is_some() {
+        custom_fields.shift_remove("x-nullable");
+    }
+
+    let r#type = match (schema.r#type, nullable) {
+        (Some(Either::Left(single)), Some(true)) => {
+            Some(Either::Right(Box::new(vec![single, "null".to_string()])))
+        }
+        (Some(Either::Right(mut multiple)), Some(true)) => {
+            if !multiple.iter().any(|value| value == "null") {
+                multiple.push("null".to_string());
+            }
+            Some(Either::Right(multiple))
+        }
+        (r#type, _) => r#type,
+    };
+
     let all_of = schema
         .all_of
         .map(|all_of| all_of.into_iter().map(convert_schema_ref).collect());
@@ -622,9 +901,9 @@ fn convert_schema(schema: Schema) -> core::Schema {
         title: schema.title,
         multiple_of: schema.multiple_of,
         maximum: schema.maximum,
-        exclusive_maximum: schema.exclusive_maximum,
+        exclusive_maximum: schema.exclusive_maximum.map(Either::Left),
         minimum: schema.minimum,
-        exclusive_minimum: schema.exclusive_minimum,
+        exclusive_minimum: schema.exclusive_minimum.map(Either::Left),
         max_length: schema.max_length,
         min_length: schema.min_length,
         pattern: schema.pattern,
@@ -635,7 +914,7 @@ fn convert_schema(schema: Schema) -> core::Schema {
         min_properties: schema.min_properties,
         required: schema.required,
         r#enum: schema.r#enum,
-        r#type: schema.r#type,
+        r#type,
         all_of,
         one_of,
         any_of,
@@ -653,7 +932,8 @@ fn convert_schema(schema: Schema) -> core::Schema {
         external_docs,
         example: schema.example,
         deprecated: None,
-        custom_fields: schema.custom_fields,
+        custom_fields,
+        ..Default::default()
     }
 }
 
@@ -664,10 +944,76 @@ fn convert_external_docs(external_docs: ExternalDoc) -> core::ExternalDoc {
     }
 }
 
+fn convert_security_scheme(
+    security_scheme: SecurityScheme,
+) -> core::SecurityScheme {
+    let SecurityScheme {
+        r#type,
+        description,
+        name,
+        r#in,
+        flow,
+        authorization_url,
+        token_url,
+        scopes,
+    } = security_scheme;
+
+    // Swagger 2 has a single flow per scheme and names some flows differently
+    let flows = flow.map(|flow| {
+        let flow_object = |authorization_url, token_url| {
+            Some(core::OAuthFlow {
+                authorization_url,
+                token_url,
+                refresh_url: None,
+                scopes,
+            })
+        };
+
+        let mut flows = core::OAuthFlows {
+            implicit: None,
+            password: None,
+            client_credentials: None,
+            authorization_code: None,
+        };
+        match flow.as_str() {
+            "implicit" => {
+                flows.implicit = flow_object(authorization_url, None)
+            }
+            "password" => flows.password = flow_object(None, token_url),
+            "application" => {
+                flows.client_credentials = flow_object(None, token_url)
+            }
+            "accessCode" => {
+                flows.authorization_code =
+                    flow_object(authorization_url, token_url)
+            }
+            _ => {}
+        }
+        flows
+    });
+
+    let (r#type, scheme) = match r#type.as_str() {
+        "basic" => ("http".to_owned(), Some("basic".to_owned())),
+        _ => (r#type, None),
+    };
+
+    core::SecurityScheme {
+        r#type: Some(r#type),
+        description,
+        name,
+        r#in,
+        scheme,
+        bearer_format: None,
+        flows,
+        open_id_connect_url: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    // use crate::schema::HttpSchema;
-    // use crate::schemas::swagger2::schema::SwaggerV2;
+    use crate::core::Either;
+    use crate::schema::HttpSchema;
+    use crate::schemas::swagger2::schema::SwaggerV2;
 
     #[test]
     fn test_converter() {
@@ -680,4 +1026,262 @@ mod tests {
         // let src_schema: HttpSchema = src_schema_v2.into();
         // let tgt_schema: HttpSchema = tgt_schema_v2.into();
     }
+
+    #[test]
+    fn test_convert_security() {
+        let content = r#"{
+            "swagger": "2.0",
+            "securityDefinitions": {
+                "basicAuth": {"type": "basic"},
+                "oauth": {
+                    "type": "oauth2",
+                    "flow": "accessCode",
+                    "authorizationUrl": "https://example.com/auth",
+                    "tokenUrl": "https://example.com/token",
+                    "scopes": {"read": "Read access"}
+                }
+            },
+            "security": [{"basicAuth": []}],
+            "paths": {"/users": {
+                "get": {
+                    "tags": [],
+                    "responses": {"200": {"description": ""}}
+                },
+                "post": {
+                    "tags": [],
+                    "security": [{"oauth": ["read"]}],
+                    "responses": {"200": {"description": ""}}
+                }
+            }}
+        }"#;
+
+        let schema: HttpSchema =
+            serde_json::from_str::<SwaggerV2>(content).unwrap().into();
+
+        let schemes = schema
+            .components
+            .as_ref()
+            .and_then(|components| components.security_schemes.as_ref())
+            .unwrap();
+        let basic = schemes["basicAuth"].value().unwrap();
+        assert_eq!(basic.r#type.as_deref(), Some("http"));
+        assert_eq!(basic.scheme.as_deref(), Some("basic"));
+        let oauth = schemes["oauth"].value().unwrap();
+        let flow = oauth
+            .flows
+            .as_ref()
+            .and_then(|flows| flows.authorization_code.as_ref())
+            .unwrap();
+        assert_eq!(
+            flow.token_url.as_deref(),
+            Some("https://example.com/token")
+        );
+        assert_eq!(flow.scopes.as_ref().unwrap().len(), 1);
+
+        assert_eq!(schema.security.as_ref().unwrap().len(), 1);
+
+        let path = schema.paths.as_ref().unwrap()["/users"].value().unwrap();
+        let get = path.get.as_ref().unwrap();
+        assert_eq!(get.security_inherited, Some(true));
+        assert!(get.security.as_ref().unwrap()[0].contains_key("basicAuth"));
+        let post = path.post.as_ref().unwrap();
+        assert_eq!(post.security_inherited, Some(false));
+        assert_eq!(post.security.as_ref().unwrap()[0]["oauth"], ["read"]);
+    }
+
+    #[test]
+    fn test_convert_servers() {
+        let content = r#"{
+            "swagger": "2.0",
+            "host": "api.example.com",
+            "basePath": "/v1/",
+            "schemes": ["https", "http"],
+            "paths": {"/users": {
+                "get": {
+                    "tags": [],
+                    "schemes": ["wss"],
+                    "responses": {"200": {"description": ""}}
+                },
+                "post": {
+                    "tags": [],
+                    "schemes": ["https", "http"],
+                    "responses": {"200": {"description": ""}}
+                }
+            }}
+        }"#;
+
+        let schema: HttpSchema =
+            serde_json::from_str::<SwaggerV2>(content).unwrap().into();
+
+        let urls: Vec<_> = schema
+            .servers
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|server| server.url.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            urls,
+            ["https://api.example.com/v1", "http://api.example.com/v1"]
+        );
+
+        let path = schema.paths.as_ref().unwrap()["/users"].value().unwrap();
+        let get = path.get.as_ref().unwrap();
+        assert_eq!(
+            get.servers.as_ref().unwrap()[0].url.as_deref(),
+            Some("wss://api.example.com/v1")
+        );
+        assert!(path.post.as_ref().unwrap().servers.is_none());
+
+        let schema: HttpSchema = serde_json::from_str::<SwaggerV2>(
+            r#"{"swagger": "2.0", "basePath": "/v2"}"#,
+        )
+        .unwrap()
+        .into();
+        assert_eq!(
+            schema.servers.as_ref().unwrap()[0].url.as_deref(),
+            Some("/v2")
+        );
+
+        let schema: HttpSchema =
+            serde_json::from_str::<SwaggerV2>(r#"{"swagger": "2.0"}"#)
+                .unwrap()
+                .into();
+        assert!(schema.servers.is_none());
+    }
+
+    #[test]
+    fn test_convert_collection_format_and_form_data() {
+        let content = r#"{
+            "swagger": "2.0",
+            "paths": {"/users/{ids}": {
+                "parameters": [{
+                    "name": "ids", "in": "path", "required": true,
+                    "type": "array", "items": {"type": "string"}
+                }],
+                "get": {
+                    "tags": [],
+                    "parameters": [
+                        {
+                            "name": "tags", "in": "query", "type": "array",
+                            "collectionFormat": "multi",
+                            "items": {"type": "string"}
+                        },
+                        {
+                            "name": "fields", "in": "query", "type": "array",
+                            "collectionFormat": "pipes",
+                            "items": {"type": "string"}
+                        },
+                        {"name": "limit", "in": "query", "type": "integer"}
+                    ],
+                    "responses": {"200": {"description": ""}}
+                },
+                "post": {
+                    "tags": [],
+                    "parameters": [
+                        {
+                            "name": "avatar", "in": "formData",
+                            "type": "file", "required": true
+                        },
+                        {
+                            "name": "roles", "in": "formData", "type": "array",
+                            "items": {"type": "string"}
+                        }
+                    ],
+                    "responses": {"200": {"description": ""}}
+                }
+            }}
+        }"#;
+
+        let schema: HttpSchema =
+            serde_json::from_str::<SwaggerV2>(content).unwrap().into();
+
+        let path = schema.paths.as_ref().unwrap()["/users/{ids}"]
+            .value()
+            .unwrap();
+
+        let get = path.get.as_ref().unwrap();
+        let styles: Vec<_> = get
+            .parameters
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|parameter| {
+                let parameter = parameter.value().unwrap();
+                (
+                    parameter.name.as_str(),
+                    parameter.style.as_deref(),
+                    parameter.explode,
+                )
+            })
+            .collect();
+        assert_eq!(
+            styles,
+            [
+                ("tags", Some("form"), Some(true)),
+                ("fields", Some("pipeDelimited"), Some(false)),
+                ("limit", None, None),
+                ("ids", Some("simple"), Some(false)),
+            ]
+        );
+
+        let post = path.post.as_ref().unwrap();
+        let request_body =
+            post.request_body.as_ref().unwrap().value().unwrap();
+        assert_eq!(request_body.required, Some(true));
+
+        let content = request_body.content.as_ref().unwrap();
+        assert_eq!(
+            content.keys().collect::<Vec<_>>(),
+            ["multipart/form-data"]
+        );
+
+        let media_type = &content["multipart/form-data"];
+        let schema = media_type.schema.as_ref().unwrap().value().unwrap();
+        assert_eq!(schema.required, Some(vec!["avatar".to_owned()]));
+
+        let properties = schema.properties.as_ref().unwrap();
+        let avatar = properties["avatar"].value().unwrap();
+        assert_eq!(avatar.format.as_deref(), Some("binary"));
+
+        let encoding = media_type.encoding.as_ref().unwrap();
+        assert_eq!(encoding["roles"].style.as_deref(), Some("form"));
+        assert_eq!(encoding["roles"].explode, Some(false));
+    }
+
+    #[test]
+    fn test_convert_x_nullable() {
+        let content = r#"{
+            "swagger": "2.0",
+            "paths": {},
+            "definitions": {"Pet": {
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string", "x-nullable": true},
+                    "tag": {"type": "string", "x-nullable": false}
+                }
+            }}
+        }"#;
+
+        let schema: HttpSchema =
+            serde_json::from_str::<SwaggerV2>(content).unwrap().into();
+
+        let components = schema.components.as_ref().unwrap();
+        let pet = components.schemas.as_ref().unwrap()["Pet"].value().unwrap();
+        let properties = pet.properties.as_ref().unwrap();
+
+        let name = properties["name"].value().unwrap();
+        assert!(matches!(
+            name.r#type.as_ref(),
+            Some(Either::Right(types)) if **types == ["string", "null"]
+        ));
+        assert!(name.custom_fields.is_empty());
+
+        let tag = properties["tag"].value().unwrap();
+        assert!(matches!(
+            tag.r#type.as_ref(),
+            Some(Either::Left(r#type)) if r#type == "string"
+        ));
+        assert!(tag.custom_fields.is_empty());
+    }
 }
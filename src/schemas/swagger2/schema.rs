@@ -183,11 +183,15 @@ pub struct Operation {
     pub produces: Option<Vec<String>>,
 
     pub parameters: Option<Vec<MayBeRef200<Parameter>>>,
+    #[serde(deserialize_with = "crate::schemas::de::scalar_key_map")]
     pub responses: IndexMap<String, MayBeRef200<Response>>,
 
     pub schemes: Option<Vec<String>>,
     pub deprecated: Option<bool>,
-    // pub security: Option<Security>,
+    pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
+
+    #[serde(flatten)]
+    pub custom_fields: IndexMap<String, Value>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -202,6 +206,19 @@ pub struct Path {
     pub parameters: Option<Vec<MayBeRef200<Parameter>>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityScheme {
+    pub r#type: String,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    pub r#in: Option<String>,
+    pub flow: Option<String>,
+    pub authorization_url: Option<String>,
+    pub token_url: Option<String>,
+    pub scopes: Option<IndexMap<String, String>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Contact {
     pub name: Option<String>,
@@ -234,6 +251,10 @@ pub struct SwaggerV2 {
     pub swagger: String,
     pub info: Option<Info>,
 
+    pub host: Option<String>,
+    pub base_path: Option<String>,
+    pub schemes: Option<Vec<String>>,
+
     pub consumes: Option<Vec<String>>,
     pub produces: Option<Vec<String>>,
 
@@ -241,6 +262,8 @@ pub struct SwaggerV2 {
     pub definitions: Option<IndexMap<String, Schema>>,
     pub parameters: Option<IndexMap<String, Parameter>>,
     pub responses: Option<IndexMap<String, Response>>,
+    pub security_definitions: Option<IndexMap<String, SecurityScheme>>,
+    pub security: Option<Vec<IndexMap<String, Vec<String>>>>,
 }
 
 impl SwaggerV2 {
@@ -4,9 +4,9 @@ use std::cell::RefCell;
 
 use crate::schema_diff::{
     deref_parameter_diff, deref_request_body_diff, deref_response_diff,
-    deref_schema_diff, HttpSchemaDiff, MayBeRefDiff, MediaTypeDiff,
-    OperationDiff, ParameterDiff, PathDiff, RequestBodyDiff, ResponseDiff,
-    SchemaDiff,
+    deref_schema_diff, CallbackDiff, HeaderDiff, HttpSchemaDiff, MayBeRefDiff,
+    MediaTypeDiff, OperationDiff, ParameterDiff, PathDiff, RequestBodyDiff,
+    ResponseDiff, SchemaDiff, SecuritySchemeDiff,
 };
 use crate::schema_diff_utils::PathsMapPathResolver;
 
@@ -25,6 +25,13 @@ pub trait DiffVisitor<'s> {
     ) -> bool {
         true
     }
+    fn visit_webhooks(
+        &self,
+        pointer: &PathPointer,
+        webhooks_diff_result: &'s DiffResult<MapDiff<MayBeRefDiff<PathDiff>>>,
+    ) -> bool {
+        true
+    }
     fn visit_path(
         &self,
         pointer: &PathPointer,
@@ -33,6 +40,15 @@ pub trait DiffVisitor<'s> {
     ) -> bool {
         true
     }
+    fn visit_security_schemes(
+        &self,
+        pointer: &PathPointer,
+        security_schemes_diff_result: &'s DiffResult<
+            MapDiff<MayBeRefDiff<SecuritySchemeDiff>>,
+        >,
+    ) -> bool {
+        true
+    }
 
     fn visit_schema_ref(
         &self,
@@ -102,6 +118,14 @@ pub trait DiffVisitor<'s> {
     ) -> bool {
         false
     }
+
+    fn visit_headers(
+        &self,
+        pointer: &PathPointer,
+        headers_diff_result: &'s DiffResult<MapDiff<MayBeRefDiff<HeaderDiff>>>,
+    ) -> bool {
+        false
+    }
     fn visit_media_type(
         &self,
         pointer: &PathPointer,
@@ -135,6 +159,23 @@ pub trait DiffVisitor<'s> {
     ) -> bool {
         false
     }
+
+    fn visit_callbacks(
+        &self,
+        pointer: &PathPointer,
+        callbacks_diff_result: &'s DiffResult<MapDiff<CallbackDiff>>,
+    ) -> bool {
+        false
+    }
+
+    fn visit_security_scheme(
+        &self,
+        pointer: &PathPointer,
+        name: &str,
+        security_scheme_diff_result: &'s DiffResult<SecuritySchemeDiff>,
+    ) -> bool {
+        false
+    }
 }
 
 pub fn dispatch_visitor<'s, T: DiffVisitor<'s>>(
@@ -150,6 +191,29 @@ pub fn dispatch_visitor<'s, T: DiffVisitor<'s>>(
     );
 
     dispatch_paths(root, &pointer, &root.paths, visitor);
+
+    let pointer = PathPointer::new(
+        &root.webhooks,
+        Some("webhooks"),
+        Some(PathPointerScope::Webhooks),
+    );
+
+    dispatch_webhooks(root, &pointer, &root.webhooks, visitor);
+
+    if let Some(components) = root.components.get() {
+        let pointer =
+            PathPointer::new(&root.components, Some("components"), None).add(
+                &components.security_schemes,
+                "securitySchemes",
+                Some(PathPointerScope::SecuritySchemes),
+            );
+
+        dispatch_security_schemes(
+            &pointer,
+            &components.security_schemes,
+            visitor,
+        );
+    }
 }
 
 pub fn dispatch_paths<'s, T>(
@@ -183,6 +247,70 @@ pub fn dispatch_paths<'s, T>(
     }
 }
 
+pub fn dispatch_webhooks<'s, T>(
+    root: &'s HttpSchemaDiff,
+    pointer: &PathPointer,
+    webhooks_diff_result: &'s DiffResult<MapDiff<MayBeRefDiff<PathDiff>>>,
+    visitor: &T,
+) where
+    T: DiffVisitor<'s>,
+{
+    if !visitor.visit_webhooks(pointer, webhooks_diff_result) {
+        return;
+    }
+
+    if let Some(webhooks) = webhooks_diff_result.get() {
+        for (name, may_be_path_diff_result) in webhooks.iter() {
+            let pointer = pointer.add_context(may_be_path_diff_result);
+            if let Some(MayBeRefDiff::Value(path_diff_result)) =
+                may_be_path_diff_result.get()
+            {
+                let pointer = pointer.add(
+                    &**path_diff_result,
+                    name,
+                    Some(PathPointerScope::Path),
+                );
+                dispatch_path(root, &pointer, name, path_diff_result, visitor)
+            }
+        }
+    }
+}
+
+pub fn dispatch_security_schemes<'s, T: DiffVisitor<'s>>(
+    pointer: &PathPointer,
+    security_schemes_diff_result: &'s DiffResult<
+        MapDiff<MayBeRefDiff<SecuritySchemeDiff>>,
+    >,
+    visitor: &T,
+) {
+    if !visitor.visit_security_schemes(pointer, security_schemes_diff_result) {
+        return;
+    }
+
+    if let Some(security_schemes) = security_schemes_diff_result.get() {
+        for (name, may_be_security_scheme_diff_result) in
+            security_schemes.iter()
+        {
+            let pointer =
+                pointer.add_context(may_be_security_scheme_diff_result);
+            if let Some(MayBeRefDiff::Value(security_scheme_diff_result)) =
+                may_be_security_scheme_diff_result.get()
+            {
+                let pointer = pointer.add(
+                    &**security_scheme_diff_result,
+                    name,
+                    Some(PathPointerScope::SecurityScheme),
+                );
+                visitor.visit_security_scheme(
+                    &pointer,
+                    name,
+                    security_scheme_diff_result,
+                );
+            }
+        }
+    }
+}
+
 pub fn dispatch_path<'s, T: DiffVisitor<'s>>(
     root: &'s HttpSchemaDiff,
     pointer: &PathPointer,
@@ -309,7 +437,15 @@ pub fn dispatch_operation<'s, T: DiffVisitor<'s>>(
                                         5,
                                     );
                                     // response_diff.headers
-                                    // TODO
+                                    let p = pointer.add(
+                                        &response_diff.headers,
+                                        "headers",
+                                        None,
+                                    );
+                                    visitor.visit_headers(
+                                        &p,
+                                        &response_diff.headers,
+                                    );
                                 }
                             }
                         }
@@ -328,11 +464,22 @@ pub fn dispatch_operation<'s, T: DiffVisitor<'s>>(
                 for (idx, may_be_parameter_diff_result) in
                     parameters.iter().enumerate()
                 {
-                    let pointer = p.add(
-                        may_be_parameter_diff_result,
-                        idx.to_string(),
-                        None,
-                    );
+                    let name = may_be_parameter_diff_result
+                        .get()
+                        .and_then(|may_be_parameter| {
+                            deref_parameter_diff(root, may_be_parameter)
+                        })
+                        .and_then(|parameter_diff| parameter_diff.get())
+                        .map(|parameter| {
+                            format!("{}:{}", parameter.r#in, parameter.name)
+                        });
+                    let pointer = p
+                        .add(
+                            may_be_parameter_diff_result,
+                            idx.to_string(),
+                            None,
+                        )
+                        .with_name(name);
                     if visitor.visit_parameter_ref(
                         &pointer,
                         may_be_parameter_diff_result,
@@ -345,14 +492,75 @@ pub fn dispatch_operation<'s, T: DiffVisitor<'s>>(
                             {
                                 let pointer =
                                     pointer.add_context(parameter_diff);
-                                visitor
-                                    .visit_parameter(&pointer, parameter_diff);
+                                if !visitor
+                                    .visit_parameter(&pointer, parameter_diff)
+                                {
+                                    continue;
+                                }
+
+                                if let Some(parameter) = parameter_diff.get() {
+                                    let pointer = pointer.add(
+                                        &parameter.schema,
+                                        "schema",
+                                        Some(PathPointerScope::Schema),
+                                    );
+                                    dispatch_schema(
+                                        root,
+                                        &pointer,
+                                        &parameter.schema,
+                                        visitor,
+                                        5,
+                                    );
+                                }
                             }
                         }
                     }
                 }
             }
         }
+
+        // operation.callbacks
+        let p = pointer.add(
+            &operation.callbacks,
+            "callbacks",
+            Some(PathPointerScope::Callbacks),
+        );
+        if visitor.visit_callbacks(&p, &operation.callbacks) {
+            if let Some(callbacks) = operation.callbacks.get() {
+                for (name, callback_diff_result) in callbacks.iter() {
+                    let pointer = p.add(
+                        callback_diff_result,
+                        name,
+                        Some(PathPointerScope::Callback),
+                    );
+                    let Some(callback) = callback_diff_result.get() else {
+                        continue;
+                    };
+                    for (expression, may_be_path_diff_result) in
+                        callback.iter()
+                    {
+                        let pointer =
+                            pointer.add_context(may_be_path_diff_result);
+                        if let Some(MayBeRefDiff::Value(path_diff_result)) =
+                            may_be_path_diff_result.get()
+                        {
+                            let pointer = pointer.add(
+                                &**path_diff_result,
+                                expression,
+                                Some(PathPointerScope::Path),
+                            );
+                            dispatch_path(
+                                root,
+                                &pointer,
+                                expression,
+                                path_diff_result,
+                                visitor,
+                            )
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -489,7 +697,7 @@ pub fn dispatch_schema<'s, T: DiffVisitor<'s>>(
                         Some(PathPointerScope::SchemaOneOf),
                     );
                     for (idx, may_be_schema_diff_result) in
-                        may_be_schema_vec.iter().enumerate()
+                        may_be_schema_vec.positioned()
                     {
                         let pointer = pointer.add(
                             may_be_schema_diff_result,
@@ -514,7 +722,7 @@ pub fn dispatch_schema<'s, T: DiffVisitor<'s>>(
                         Some(PathPointerScope::SchemaAnyOf),
                     );
                     for (idx, may_be_schema_diff_result) in
-                        may_be_schema_vec.iter().enumerate()
+                        may_be_schema_vec.positioned()
                     {
                         let pointer = pointer.add(
                             may_be_schema_diff_result,
@@ -539,7 +747,7 @@ pub fn dispatch_schema<'s, T: DiffVisitor<'s>>(
                         Some(PathPointerScope::SchemaAllOf),
                     );
                     for (idx, may_be_schema_diff_result) in
-                        may_be_schema_vec.iter().enumerate()
+                        may_be_schema_vec.positioned()
                     {
                         let pointer = pointer.add(
                             may_be_schema_diff_result,
@@ -591,6 +799,205 @@ pub fn dispatch_schema<'s, T: DiffVisitor<'s>>(
                         );
                     }
                 }
+
+                // schema.prefixItems
+                if let Some(may_be_schema_vec) = schema.prefix_items.get() {
+                    let pointer = pointer.add(
+                        &schema.prefix_items,
+                        "prefixItems",
+                        Some(PathPointerScope::SchemaPrefixItems),
+                    );
+                    for (idx, may_be_schema_diff_result) in
+                        may_be_schema_vec.iter().enumerate()
+                    {
+                        let pointer = pointer.add(
+                            may_be_schema_diff_result,
+                            idx.to_string(),
+                            None,
+                        );
+                        dispatch_schema(
+                            root,
+                            &pointer,
+                            may_be_schema_diff_result,
+                            visitor,
+                            depth - 1,
+                        );
+                    }
+                }
+
+                // schema.contains
+                if !schema.contains.is_none() {
+                    let pointer = pointer.add(
+                        &*schema.contains,
+                        "contains",
+                        Some(PathPointerScope::SchemaContains),
+                    );
+                    dispatch_schema(
+                        root,
+                        &pointer,
+                        &schema.contains,
+                        visitor,
+                        depth - 1,
+                    );
+                }
+
+                // schema.patternProperties
+                if let Some(may_be_schema_map) =
+                    schema.pattern_properties.get()
+                {
+                    let pointer = pointer.add(
+                        &schema.pattern_properties,
+                        "patternProperties",
+                        Some(PathPointerScope::SchemaPatternProperties),
+                    );
+                    for (key, may_be_schema_diff_result) in
+                        may_be_schema_map.iter()
+                    {
+                        let pointer =
+                            pointer.add(may_be_schema_diff_result, key, None);
+                        dispatch_schema(
+                            root,
+                            &pointer,
+                            may_be_schema_diff_result,
+                            visitor,
+                            depth - 1,
+                        );
+                    }
+                }
+
+                // schema.propertyNames
+                if !schema.property_names.is_none() {
+                    let pointer = pointer.add(
+                        &*schema.property_names,
+                        "propertyNames",
+                        Some(PathPointerScope::SchemaPropertyNames),
+                    );
+                    dispatch_schema(
+                        root,
+                        &pointer,
+                        &schema.property_names,
+                        visitor,
+                        depth - 1,
+                    );
+                }
+
+                // schema.unevaluatedProperties
+                if let Some(either_schema) =
+                    schema.unevaluated_properties.get()
+                {
+                    let pointer = pointer.add(
+                        &schema.unevaluated_properties,
+                        "unevaluatedProperties",
+                        Some(PathPointerScope::SchemaUnevaluatedProperties),
+                    );
+                    if let EitherDiff::Right(may_be_schema_diff_result)
+                    | EitherDiff::ToRight(may_be_schema_diff_result) =
+                        either_schema
+                    {
+                        let pointer =
+                            pointer.add_context(&**may_be_schema_diff_result);
+                        dispatch_schema(
+                            root,
+                            &pointer,
+                            may_be_schema_diff_result,
+                            visitor,
+                            depth - 1,
+                        );
+                    }
+                }
+
+                // schema.if
+                if !schema.r#if.is_none() {
+                    let pointer = pointer.add(
+                        &*schema.r#if,
+                        "if",
+                        Some(PathPointerScope::SchemaIf),
+                    );
+                    dispatch_schema(
+                        root,
+                        &pointer,
+                        &schema.r#if,
+                        visitor,
+                        depth - 1,
+                    );
+                }
+
+                // schema.then
+                if !schema.then.is_none() {
+                    let pointer = pointer.add(
+                        &*schema.then,
+                        "then",
+                        Some(PathPointerScope::SchemaThen),
+                    );
+                    dispatch_schema(
+                        root,
+                        &pointer,
+                        &schema.then,
+                        visitor,
+                        depth - 1,
+                    );
+                }
+
+                // schema.else
+                if !schema.r#else.is_none() {
+                    let pointer = pointer.add(
+                        &*schema.r#else,
+                        "else",
+                        Some(PathPointerScope::SchemaElse),
+                    );
+                    dispatch_schema(
+                        root,
+                        &pointer,
+                        &schema.r#else,
+                        visitor,
+                        depth - 1,
+                    );
+                }
+
+                // schema.dependentSchemas
+                if let Some(may_be_schema_map) = schema.dependent_schemas.get()
+                {
+                    let pointer = pointer.add(
+                        &schema.dependent_schemas,
+                        "dependentSchemas",
+                        Some(PathPointerScope::SchemaDependentSchemas),
+                    );
+                    for (key, may_be_schema_diff_result) in
+                        may_be_schema_map.iter()
+                    {
+                        let pointer =
+                            pointer.add(may_be_schema_diff_result, key, None);
+                        dispatch_schema(
+                            root,
+                            &pointer,
+                            may_be_schema_diff_result,
+                            visitor,
+                            depth - 1,
+                        );
+                    }
+                }
+
+                // schema.$defs
+                if let Some(may_be_schema_map) = schema.defs.get() {
+                    let pointer = pointer.add(
+                        &schema.defs,
+                        "$defs",
+                        Some(PathPointerScope::SchemaDefs),
+                    );
+                    for (key, may_be_schema_diff_result) in
+                        may_be_schema_map.iter()
+                    {
+                        let pointer =
+                            pointer.add(may_be_schema_diff_result, key, None);
+                        dispatch_schema(
+                            root,
+                            &pointer,
+                            may_be_schema_diff_result,
+                            visitor,
+                            depth - 1,
+                        );
+                    }
+                }
             }
         }
     }
@@ -651,6 +1058,16 @@ impl<'a, 's> DiffVisitor<'s> for MergedVisitor<'a, 's> {
         self.visit(pointer, |v| v.visit_paths(pointer, paths_diff_result))
     }
 
+    fn visit_webhooks(
+        &self,
+        pointer: &PathPointer,
+        webhooks_diff_result: &'s DiffResult<MapDiff<MayBeRefDiff<PathDiff>>>,
+    ) -> bool {
+        self.visit(pointer, |v| {
+            v.visit_webhooks(pointer, webhooks_diff_result)
+        })
+    }
+
     fn visit_path(
         &self,
         pointer: &PathPointer,
@@ -660,6 +1077,38 @@ impl<'a, 's> DiffVisitor<'s> for MergedVisitor<'a, 's> {
         self.visit(pointer, |v| v.visit_path(pointer, path, path_diff_result))
     }
 
+    fn visit_schema_ref(
+        &self,
+        pointer: &PathPointer,
+        may_be_ref: &'s DiffResult<MayBeRefDiff<SchemaDiff>>,
+    ) -> bool {
+        self.visit(pointer, |v| v.visit_schema_ref(pointer, may_be_ref))
+    }
+
+    fn visit_response_ref(
+        &self,
+        pointer: &PathPointer,
+        may_be_ref: &'s DiffResult<MayBeRefDiff<ResponseDiff>>,
+    ) -> bool {
+        self.visit(pointer, |v| v.visit_response_ref(pointer, may_be_ref))
+    }
+
+    fn visit_parameter_ref(
+        &self,
+        pointer: &PathPointer,
+        may_be_ref: &'s DiffResult<MayBeRefDiff<ParameterDiff>>,
+    ) -> bool {
+        self.visit(pointer, |v| v.visit_parameter_ref(pointer, may_be_ref))
+    }
+
+    fn visit_request_body_ref(
+        &self,
+        pointer: &PathPointer,
+        may_be_ref: &'s DiffResult<MayBeRefDiff<RequestBodyDiff>>,
+    ) -> bool {
+        self.visit(pointer, |v| v.visit_request_body_ref(pointer, may_be_ref))
+    }
+
     fn visit_operation(
         &self,
         pointer: &PathPointer,
@@ -681,6 +1130,18 @@ impl<'a, 's> DiffVisitor<'s> for MergedVisitor<'a, 's> {
         })
     }
 
+    fn visit_responses(
+        &self,
+        pointer: &PathPointer,
+        responses_diff_result: &'s DiffResult<
+            MapDiff<MayBeRefDiff<ResponseDiff>>,
+        >,
+    ) -> bool {
+        self.visit(pointer, |v| {
+            v.visit_responses(pointer, responses_diff_result)
+        })
+    }
+
     fn visit_media_types(
         &self,
         pointer: &PathPointer,
@@ -691,6 +1152,14 @@ impl<'a, 's> DiffVisitor<'s> for MergedVisitor<'a, 's> {
         })
     }
 
+    fn visit_headers(
+        &self,
+        pointer: &PathPointer,
+        headers_diff_result: &'s DiffResult<MapDiff<MayBeRefDiff<HeaderDiff>>>,
+    ) -> bool {
+        self.visit(pointer, |v| v.visit_headers(pointer, headers_diff_result))
+    }
+
     fn visit_media_type(
         &self,
         pointer: &PathPointer,
@@ -730,20 +1199,56 @@ impl<'a, 's> DiffVisitor<'s> for MergedVisitor<'a, 's> {
     ) -> bool {
         self.visit(pointer, |v| v.visit_schema(pointer, schema_diff_result))
     }
+
+    fn visit_callbacks(
+        &self,
+        pointer: &PathPointer,
+        callbacks_diff_result: &'s DiffResult<MapDiff<CallbackDiff>>,
+    ) -> bool {
+        self.visit(pointer, |v| {
+            v.visit_callbacks(pointer, callbacks_diff_result)
+        })
+    }
+
+    fn visit_security_schemes(
+        &self,
+        pointer: &PathPointer,
+        security_schemes_diff_result: &'s DiffResult<
+            MapDiff<MayBeRefDiff<SecuritySchemeDiff>>,
+        >,
+    ) -> bool {
+        self.visit(pointer, |v| {
+            v.visit_security_schemes(pointer, security_schemes_diff_result)
+        })
+    }
+
+    fn visit_security_scheme(
+        &self,
+        pointer: &PathPointer,
+        name: &str,
+        security_scheme_diff_result: &'s DiffResult<SecuritySchemeDiff>,
+    ) -> bool {
+        self.visit(pointer, |v| {
+            v.visit_security_scheme(pointer, name, security_scheme_diff_result)
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::cell::RefCell;
+
     use crate::core::{DiffResult, MapDiff};
     use crate::get_schema_diff;
     use crate::path_pointer::{PathPointer, PathPointerScope};
     use crate::schema::HttpSchema;
     use crate::schema_diff::{
-        HttpSchemaDiff, MayBeRefDiff, OperationDiff, PathDiff,
-        RequestBodyDiff, ResponseDiff,
+        MayBeRefDiff, MediaTypeDiff, OperationDiff, PathDiff, RequestBodyDiff,
+        ResponseDiff, SchemaDiff,
     };
     use crate::schema_diff_utils::PathsMapPathResolver;
     use crate::schemas::openapi303::schema::OpenApi303;
+    use crate::try_deserialize_schema;
     use crate::visitor::{dispatch_visitor, DiffVisitor};
 
     #[test]
@@ -762,9 +1267,9 @@ mod test {
 
         let diff = get_schema_diff(src_schema, tgt_schema);
 
-        struct PointerLevelVisitor<'s>(&'s HttpSchemaDiff);
+        struct PointerLevelVisitor;
 
-        impl<'s> DiffVisitor<'s> for PointerLevelVisitor<'s> {
+        impl<'s> DiffVisitor<'s> for PointerLevelVisitor {
             fn visit_paths(
                 &self,
                 pointer: &PathPointer,
@@ -826,9 +1331,135 @@ mod test {
             }
         }
 
-        dispatch_visitor(
-            diff.get().unwrap(),
-            &PointerLevelVisitor(&diff.get().unwrap()),
+        dispatch_visitor(diff.get().unwrap(), &PointerLevelVisitor);
+    }
+
+    #[test]
+    fn test_json_schema_keywords() {
+        let schema = |min_contains: usize, pattern_type: &str| {
+            format!(
+                r##"{{
+                "openapi": "3.1.0",
+                "paths": {{"/items": {{"post": {{
+                    "requestBody": {{"content": {{"application/json": {{
+                        "schema": {{
+                    "type": "object",
+                    "const": {{"kind": "item"}},
+                    "prefixItems": [{{"type": "string"}}],
+                    "contains": {{"type": "integer"}},
+                    "minContains": {min_contains},
+                    "patternProperties": {{"^x-": {{"type": "{pattern_type}"}}}},
+                    "propertyNames": {{"maxLength": 8}},
+                    "unevaluatedProperties": {{"type": "string"}},
+                    "if": {{"required": ["a"]}},
+                    "then": {{"required": ["b"]}},
+                    "else": {{"required": ["c"]}},
+                    "dependentRequired": {{"a": ["b"]}},
+                    "dependentSchemas": {{"a": {{"minProperties": 2}}}},
+                    "$defs": {{"Extra": {{"type": "string"}}}},
+                    "examples": [{{"a": 1}}]
+                        }}
+                    }}}}}},
+                    "responses": {{}}
+                }}}}}}
+            }}"##
+            )
+        };
+
+        let (src, tgt) =
+            try_deserialize_schema(&schema(1, "string"), &schema(2, "number"))
+                .unwrap();
+
+        let item = src.paths.as_ref().unwrap()["/items"].value().unwrap();
+        let item = item.post.as_ref().unwrap().request_body.as_ref().unwrap();
+        let item = &item.value().unwrap().content.as_ref().unwrap()
+            ["application/json"];
+        let item = item.schema.as_ref().unwrap().value().unwrap();
+        assert!(item.custom_fields.is_empty());
+        assert_eq!(item.dependent_required.as_ref().unwrap()["a"], ["b"]);
+        assert_eq!(item.defs.as_ref().unwrap().len(), 1);
+
+        let diff = get_schema_diff(src, tgt);
+
+        #[derive(Default)]
+        struct SchemaPathsVisitor {
+            paths: RefCell<Vec<(String, bool)>>,
+        }
+
+        impl<'s> DiffVisitor<'s> for SchemaPathsVisitor {
+            fn visit_operation(
+                &self,
+                _: &PathPointer,
+                _: &str,
+                _: &'s DiffResult<OperationDiff>,
+            ) -> bool {
+                true
+            }
+
+            fn visit_request_body(
+                &self,
+                _: &PathPointer,
+                _: &'s DiffResult<RequestBodyDiff>,
+            ) -> bool {
+                true
+            }
+
+            fn visit_media_types(
+                &self,
+                _: &PathPointer,
+                _: &'s DiffResult<MapDiff<MediaTypeDiff>>,
+            ) -> bool {
+                true
+            }
+
+            fn visit_media_type(
+                &self,
+                _: &PathPointer,
+                _: &'s DiffResult<MediaTypeDiff>,
+            ) -> bool {
+                true
+            }
+
+            fn visit_schema(
+                &self,
+                pointer: &PathPointer,
+                schema_diff_result: &'s DiffResult<SchemaDiff>,
+            ) -> bool {
+                self.paths.borrow_mut().push((
+                    pointer.get_path(),
+                    schema_diff_result.is_updated(),
+                ));
+                true
+            }
+        }
+
+        let visitor = SchemaPathsVisitor::default();
+        dispatch_visitor(diff.get().unwrap(), &visitor);
+
+        let prefix = "paths//items/post/requestBody/content/application/json";
+        let paths: Vec<_> = visitor
+            .paths
+            .into_inner()
+            .into_iter()
+            .map(|(path, updated)| {
+                (path.trim_start_matches(prefix).to_string(), updated)
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("/schema".to_string(), true),
+                ("/schema/prefixItems/0".to_string(), false),
+                ("/schema/contains".to_string(), false),
+                ("/schema/patternProperties/^x-".to_string(), true),
+                ("/schema/propertyNames".to_string(), false),
+                ("/schema/unevaluatedProperties".to_string(), false),
+                ("/schema/if".to_string(), false),
+                ("/schema/then".to_string(), false),
+                ("/schema/else".to_string(), false),
+                ("/schema/dependentSchemas/a".to_string(), false),
+                ("/schema/$defs/Extra".to_string(), false),
+            ]
         );
     }
 }
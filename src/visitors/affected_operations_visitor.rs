@@ -42,7 +42,7 @@ impl<'s> SharedChangesVisitor<'s> {
 }
 
 impl<'s> DiffVisitor<'s> for SharedChangesVisitor<'s> {
-    /// Actual code
+    // Actual code
 
     fn visit_schema_ref(
         &self,